@@ -0,0 +1,335 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+/// A vector with a fixed capacity of N, stored inline. Can be dereferenced into a regular slice on demand.
+///
+/// ```
+/// use cl_array_ext::ArrayVec;
+/// let mut a = ArrayVec::<i32, 3>::new();
+/// a.push(1);
+/// a.push(2);
+///
+/// assert_eq!(a, [1, 2]);
+/// assert_eq!(a.try_push(3), Ok(()));
+/// assert_eq!(a.try_push(4), Err(4));
+/// ```
+pub struct ArrayVec<T, const N: usize> {
+    arr: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    /// Create a new empty vector
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            arr: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// The maximum number of elements the vector can hold
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns true if there is no more space in the vector
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Add a value onto the end of the vector
+    /// # Panics
+    /// Panics if the vector is already full
+    pub fn push(&mut self, value: T) {
+        assert!(self.try_push(value).is_ok(), "ArrayVec is full");
+    }
+
+    /// Add a value onto the end of the vector
+    /// # Errors
+    /// Returns the value back if the vector is already full
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            Err(value)
+        } else {
+            unsafe { self.push_unchecked(value) };
+            Ok(())
+        }
+    }
+
+    /// Add a value onto the end of the vector
+    /// # Safety
+    /// The vector must not be full, otherwise this will result in UB
+    pub unsafe fn push_unchecked(&mut self, value: T) {
+        self.arr.get_unchecked_mut(self.len).write(value);
+        self.len += 1;
+    }
+
+    /// Remove the last value from the vector, if there is one
+    pub fn pop(&mut self) -> Option<T> {
+        self.len = self.len.checked_sub(1)?;
+        unsafe { Some(self.arr.get_unchecked(self.len).assume_init_read()) }
+    }
+
+    /// Convert the vector into an array, if it is full
+    /// # Errors
+    /// Returns the vector back if it is not full
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayVec;
+    /// let mut a = ArrayVec::<i32, 2>::new();
+    /// a.push(1);
+    /// let mut a = a.into_array().unwrap_err();
+    /// a.push(2);
+    /// assert_eq!(a.into_array(), Ok([1, 2]));
+    /// ```
+    pub fn into_array(self) -> Result<[T; N], Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        unsafe {
+            Ok(crate::array_assume_init(core::ptr::read(
+                &raw const this.arr,
+            )))
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    fn drop(&mut self) {
+        unsafe { core::ptr::drop_in_place(&raw mut **self) }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut out = Self::new();
+        for value in self {
+            unsafe { out.push_unchecked(value.clone()) };
+        }
+        out
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<ArrayVec<U, M>>
+    for ArrayVec<T, N>
+{
+    fn eq(&self, other: &ArrayVec<U, M>) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]> for ArrayVec<T, N> {
+    fn eq(&self, other: &[U]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<[U; M]> for ArrayVec<T, N> {
+    fn eq(&self, other: &[U; M]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+use core::fmt;
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+use core::ops::{Deref, DerefMut};
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+    fn deref(&self) -> &Self::Target {
+        let p = self.arr.as_ptr().cast::<T>();
+        unsafe { core::slice::from_raw_parts(p, self.len) }
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        let p = self.arr.as_mut_ptr().cast::<T>();
+        unsafe { core::slice::from_raw_parts_mut(p, self.len) }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVec<T, N> {
+    type Item = T;
+    type IntoIter = ArrayVecIntoIter<T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        let this = ManuallyDrop::new(self);
+        ArrayVecIntoIter {
+            arr: unsafe { core::ptr::read(&raw const this.arr) },
+            start: 0,
+            end: this.len,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator that moves the elements out of an [`ArrayVec`], dropping any that are not consumed
+///
+/// ```
+/// use cl_array_ext::ArrayVec;
+/// let mut a = ArrayVec::<i32, 3>::new();
+/// a.push(1);
+/// a.push(2);
+/// assert!(a.into_iter().eq([1, 2]));
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub struct ArrayVecIntoIter<T, const N: usize> {
+    arr: [MaybeUninit<T>; N],
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> ArrayVecIntoIter<T, N> {
+    /// The elements that have not been consumed yet
+    pub fn as_slice(&self) -> &[T] {
+        let p = self.arr.as_ptr().cast::<T>();
+        unsafe { core::slice::from_raw_parts(p.add(self.start), self.end - self.start) }
+    }
+}
+
+impl<T, const N: usize> Iterator for ArrayVecIntoIter<T, N> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        unsafe { Some(self.arr.get_unchecked(self.start - 1).assume_init_read()) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayVecIntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        unsafe { Some(self.arr.get_unchecked(self.end).assume_init_read()) }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayVecIntoIter<T, N> {}
+impl<T, const N: usize> core::iter::FusedIterator for ArrayVecIntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for ArrayVecIntoIter<T, N> {
+    fn drop(&mut self) {
+        let len = self.end - self.start;
+        unsafe {
+            let p = self.arr.as_mut_ptr().cast::<T>().add(self.start);
+            core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(p, len));
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVecIntoIter<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ArrayVecIntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ArrayVec;
+    use std::{rc::Rc, string::String};
+
+    #[test]
+    fn array_vec() {
+        let rc = Rc::new(());
+        let mut a = ArrayVec::<_, 3>::new();
+        a.push(rc.clone());
+        a.push(rc.clone());
+        assert_eq!(a.len(), 2);
+        assert!(a.pop().is_some());
+        a.push(rc.clone());
+        a.push(rc.clone());
+        assert!(a.is_full());
+        assert!(a.try_push(rc.clone()).is_err());
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        drop(a);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn array_vec_into_iter() {
+        let rc = Rc::new(());
+        let mut a = ArrayVec::<_, 4>::new();
+        a.push(rc.clone());
+        a.push(rc.clone());
+        a.push(rc.clone());
+
+        let mut it = a.into_iter();
+        assert!(it.next().is_some());
+        assert!(it.next_back().is_some());
+        assert_eq!(it.len(), 1);
+        assert_eq!(Rc::strong_count(&rc), 2);
+
+        drop(it);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn array_vec_clone_eq() {
+        let mut a = ArrayVec::<_, 3>::new();
+        a.push(String::from("a"));
+        a.push(String::from("b"));
+
+        let b = a.clone();
+        assert_eq!(a, b);
+        assert_eq!(a, ["a", "b"]);
+        assert_eq!(a, ["a", "b"][..]);
+        assert_ne!(a, ArrayVec::<String, 2>::new());
+    }
+
+    #[test]
+    fn array_vec_into_array() {
+        let rc = Rc::new(());
+        let mut a = ArrayVec::<_, 2>::new();
+        a.push(rc.clone());
+        let mut a = a.into_array().unwrap_err();
+        a.push(rc.clone());
+
+        let arr = a.into_array().unwrap();
+        assert_eq!(Rc::strong_count(&rc), 3);
+        drop(arr);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
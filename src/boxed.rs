@@ -0,0 +1,355 @@
+use crate::SliceN;
+use alloc::{borrow::ToOwned, boxed::Box, rc::Rc, sync::Arc, vec::Vec};
+use core::mem::MaybeUninit;
+
+/// Trait that extends upon boxed arrays, reshaping them without moving them through the stack
+pub trait BoxedArrayExt<T, const N: usize>: Sized {
+    /// Split a boxed array into two smaller boxed arrays
+    ///
+    /// ```
+    /// use cl_array_ext::BoxedArrayExt;
+    /// let (a, b) = Box::new([1_i32, 2, 3, 4, 5]).array_split_at::<3>();
+    /// assert_eq!(*a, [1, 2, 3]);
+    /// assert_eq!(*b, [4, 5]);
+    /// ```
+    fn array_split_at<const M: usize>(self) -> (Box<[T; M]>, Box<[T; N - M]>)
+    where
+        [T; N - M]: Sized;
+
+    /// Take only M elements out of the boxed array
+    ///
+    /// ```
+    /// use cl_array_ext::BoxedArrayExt;
+    /// let a = Box::new([1_i32, 2, 3, 4, 5]).truncate::<3>();
+    /// assert_eq!(*a, [1, 2, 3]);
+    /// ```
+    fn truncate<const M: usize>(self) -> Box<[T; M]>
+    where
+        [T; N - M]: Sized;
+
+    /// Join two boxed arrays into one larger boxed array
+    ///
+    /// ```
+    /// use cl_array_ext::BoxedArrayExt;
+    /// let a = Box::new([1_i32, 2, 3]).append(Box::new([4, 5]));
+    /// assert_eq!(*a, [1, 2, 3, 4, 5]);
+    /// ```
+    fn append<const M: usize>(self, other: Box<[T; M]>) -> Box<[T; N + M]>;
+}
+
+/// Give up ownership of the elements of the boxed array, leaving only the allocation
+#[allow(clippy::unnecessary_box_returns)] // the allocation is the point
+fn into_uninit<T, const N: usize>(b: Box<[T; N]>) -> Box<MaybeUninit<[T; N]>> {
+    unsafe { Box::from_raw(Box::into_raw(b).cast()) }
+}
+
+impl<T, const N: usize> BoxedArrayExt<T, N> for Box<[T; N]> {
+    fn array_split_at<const M: usize>(self) -> (Box<[T; M]>, Box<[T; N - M]>)
+    where
+        [T; N - M]: Sized,
+    {
+        let arr = into_uninit(self);
+        let mut arr_a = Box::<[T; M]>::new_uninit();
+        let mut arr_b = Box::<[T; N - M]>::new_uninit();
+        let p = arr.as_ptr().cast::<T>();
+
+        unsafe {
+            core::ptr::copy(p.add(0), arr_a.as_mut_ptr().cast(), M);
+            core::ptr::copy(p.add(M), arr_b.as_mut_ptr().cast(), N - M);
+
+            (arr_a.assume_init(), arr_b.assume_init())
+        }
+    }
+
+    fn truncate<const M: usize>(self) -> Box<[T; M]>
+    where
+        [T; N - M]: Sized,
+    {
+        let mut arr = into_uninit(self);
+        let mut arr_a = Box::<[T; M]>::new_uninit();
+        let p = arr.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            core::ptr::copy(p.add(0), arr_a.as_mut_ptr().cast(), M);
+            let arr_a = arr_a.assume_init();
+
+            core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(p.add(M), N - M));
+            arr_a
+        }
+    }
+
+    fn append<const M: usize>(self, other: Box<[T; M]>) -> Box<[T; N + M]> {
+        let arr_a = into_uninit(self);
+        let arr_b = into_uninit(other);
+        let mut arr_c = Box::<[T; N + M]>::new_uninit();
+        let p = arr_c.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            core::ptr::copy(arr_a.as_ptr().cast(), p.add(0), N);
+            core::ptr::copy(arr_b.as_ptr().cast(), p.add(N), M);
+
+            arr_c.assume_init()
+        }
+    }
+}
+
+impl<T, const N: usize> SliceN<T, N> {
+    /// Convert a boxed slice into one that is guaranteed to have at least N elements
+    /// # Safety
+    /// The length of the slice must be >= N, otherwise this will result in UB
+    #[must_use]
+    pub unsafe fn from_boxed_unchecked(slice: Box<[T]>) -> Box<Self> {
+        let (p, meta) = Box::into_raw(slice).to_raw_parts();
+        Box::from_raw(core::ptr::from_raw_parts_mut(p, meta - N))
+    }
+
+    /// Converts the boxed slice back into a regular boxed slice
+    ///
+    /// ```
+    /// use cl_array_ext::SliceN;
+    /// let a: Box<SliceN<_, 2>> = vec![1, 2, 3].try_into().unwrap();
+    /// assert_eq!(a.into_boxed_slice(), vec![1, 2, 3].into_boxed_slice());
+    /// ```
+    #[must_use]
+    pub fn into_boxed_slice(self: Box<Self>) -> Box<[T]> {
+        let (p, meta) = Box::into_raw(self).to_raw_parts();
+        unsafe { Box::from_raw(core::ptr::from_raw_parts_mut(p, meta + N)) }
+    }
+
+    /// Converts the boxed slice into a vec without copying the elements
+    ///
+    /// ```
+    /// use cl_array_ext::SliceN;
+    /// let a: Box<SliceN<_, 2>> = vec![1, 2, 3].try_into().unwrap();
+    /// assert_eq!(a.into_vec(), [1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn into_vec(self: Box<Self>) -> Vec<T> {
+        self.into_boxed_slice().into_vec()
+    }
+
+    /// Maps every element of the slice into a new boxed slice, which keeps the same guarantee
+    ///
+    /// ```
+    /// use cl_array_ext::SliceN;
+    /// let a: &[_] = &[1, 2, 3];
+    /// let b: &SliceN<_, 2> = a.try_into().unwrap();
+    /// let c = b.map(|x| x * 2);
+    /// assert_eq!(c.head, [2, 4]);
+    /// assert_eq!(c.tail, [6]);
+    /// ```
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Box<SliceN<U, N>> {
+        let v: Vec<U> = self.iter().map(f).collect();
+        unsafe { SliceN::from_boxed_unchecked(v.into_boxed_slice()) }
+    }
+
+    /// Joins two slices into a new boxed slice, which keeps the combined guarantee
+    ///
+    /// ```
+    /// use cl_array_ext::SliceN;
+    /// let a: &SliceN<_, 2> = (&[1, 2, 3][..]).try_into().unwrap();
+    /// let b: &SliceN<_, 1> = (&[4, 5][..]).try_into().unwrap();
+    /// let c = a.concat_boxed(b);
+    /// assert_eq!(c.head, [1, 2, 3]);
+    /// assert_eq!(c.tail, [4, 5]);
+    /// ```
+    pub fn concat_boxed<const M: usize>(&self, other: &SliceN<T, M>) -> Box<SliceN<T, { N + M }>>
+    where
+        T: Clone,
+    {
+        let mut v = Vec::with_capacity(self.len() + other.len());
+        v.extend_from_slice(self);
+        v.extend_from_slice(other);
+        unsafe { SliceN::from_boxed_unchecked(v.into_boxed_slice()) }
+    }
+}
+
+impl<T, const N: usize> SliceN<T, N> {
+    /// Convert a shared slice into one that is guaranteed to have at least N elements,
+    /// keeping the same allocation
+    /// # Errors
+    /// If there are fewer than N elements, the original [`Arc`] is returned
+    ///
+    /// ```
+    /// use cl_array_ext::SliceN;
+    /// use std::sync::Arc;
+    /// let a: Arc<[_]> = Arc::new([1, 2, 3]);
+    /// let b = SliceN::<_, 2>::try_from_arc(a).unwrap();
+    /// assert_eq!(b.head, [1, 2]);
+    /// assert_eq!(b.into_arc_slice(), Arc::from([1, 2, 3]));
+    /// ```
+    pub fn try_from_arc(slice: Arc<[T]>) -> Result<Arc<Self>, Arc<[T]>> {
+        if slice.len() < N {
+            return Err(slice);
+        }
+        let (p, meta) = Arc::into_raw(slice).to_raw_parts();
+        unsafe { Ok(Arc::from_raw(core::ptr::from_raw_parts(p, meta - N))) }
+    }
+
+    /// Converts the shared slice back into a regular shared slice, keeping the same allocation
+    #[must_use]
+    pub fn into_arc_slice(self: Arc<Self>) -> Arc<[T]> {
+        let (p, meta) = Arc::into_raw(self).to_raw_parts();
+        unsafe { Arc::from_raw(core::ptr::from_raw_parts(p, meta + N)) }
+    }
+
+    /// Convert a shared slice into one that is guaranteed to have at least N elements,
+    /// keeping the same allocation
+    /// # Errors
+    /// If there are fewer than N elements, the original [`Rc`] is returned
+    ///
+    /// ```
+    /// use cl_array_ext::SliceN;
+    /// use std::rc::Rc;
+    /// let a: Rc<[_]> = Rc::new([1, 2, 3]);
+    /// let b = SliceN::<_, 2>::try_from_rc(a).unwrap();
+    /// assert_eq!(b.head, [1, 2]);
+    /// assert_eq!(b.into_rc_slice(), Rc::from([1, 2, 3]));
+    /// ```
+    pub fn try_from_rc(slice: Rc<[T]>) -> Result<Rc<Self>, Rc<[T]>> {
+        if slice.len() < N {
+            return Err(slice);
+        }
+        let (p, meta) = Rc::into_raw(slice).to_raw_parts();
+        unsafe { Ok(Rc::from_raw(core::ptr::from_raw_parts(p, meta - N))) }
+    }
+
+    /// Converts the shared slice back into a regular shared slice, keeping the same allocation
+    #[must_use]
+    pub fn into_rc_slice(self: Rc<Self>) -> Rc<[T]> {
+        let (p, meta) = Rc::into_raw(self).to_raw_parts();
+        unsafe { Rc::from_raw(core::ptr::from_raw_parts(p, meta + N)) }
+    }
+}
+
+impl<T, const N: usize> TryFrom<Box<[T]>> for Box<SliceN<T, N>> {
+    type Error = Box<[T]>;
+    fn try_from(value: Box<[T]>) -> Result<Self, Self::Error> {
+        if value.len() < N {
+            Err(value)
+        } else {
+            unsafe { Ok(SliceN::from_boxed_unchecked(value)) }
+        }
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for Box<SliceN<T, N>> {
+    type Error = Vec<T>;
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        if value.len() < N {
+            Err(value)
+        } else {
+            unsafe { Ok(SliceN::from_boxed_unchecked(value.into_boxed_slice())) }
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> ToOwned for SliceN<T, N> {
+    type Owned = Box<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        unsafe { Self::from_boxed_unchecked(Box::from(&**self)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedArrayExt, SliceN};
+    use alloc::{borrow::Cow, boxed::Box, sync::Arc, vec};
+    use std::rc::Rc;
+
+    #[test]
+    fn boxed() {
+        let rc = Rc::new(());
+        let a = Box::new([rc.clone(), rc.clone(), rc.clone()]);
+        let (a, b) = a.array_split_at::<1>();
+        assert_eq!(a.len(), 1);
+        assert_eq!(b.len(), 2);
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        let a = b.append(a);
+        assert_eq!(a.len(), 3);
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        let a = a.truncate::<1>();
+        assert_eq!(a.len(), 1);
+        assert_eq!(Rc::strong_count(&rc), 2);
+    }
+
+    #[test]
+    fn boxed_large() {
+        let a: Box<[u8; 1 << 24]> = alloc::vec![1; 1 << 24].try_into().unwrap();
+        let b: Box<[u8; 1 << 24]> = alloc::vec![2; 1 << 24].try_into().unwrap();
+        let c = a.append(b);
+        assert_eq!(c[(1 << 24) - 1], 1);
+        assert_eq!(c[1 << 24], 2);
+
+        let c = c.truncate::<{ 1 << 24 }>();
+        assert!(c.iter().all(|&x| x == 1));
+    }
+
+    #[test]
+    fn boxed_slice_n() {
+        let rc = Rc::new(());
+        let a = alloc::vec![rc.clone(), rc.clone(), rc.clone()];
+
+        let a = Box::<SliceN<_, 4>>::try_from(a).unwrap_err();
+        let mut b = Box::<SliceN<_, 2>>::try_from(a).unwrap();
+        assert_eq!(b.tail.len(), 1);
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        b.head[0] = Rc::new(());
+        assert_eq!(Rc::strong_count(&rc), 3);
+
+        let b = b.into_boxed_slice();
+        let b = Box::<SliceN<_, 3>>::try_from(b).unwrap();
+        assert_eq!(b.into_vec().len(), 3);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn shared_slice_n() {
+        let rc = Rc::new(());
+        let a: Rc<[_]> = Rc::new([rc.clone(), rc.clone()]);
+
+        let a = SliceN::<_, 3>::try_from_rc(a).unwrap_err();
+        let b = SliceN::<_, 1>::try_from_rc(a).unwrap();
+        let c = b.clone();
+        assert!(Rc::ptr_eq(&b.head[0], &rc));
+        assert_eq!(Rc::strong_count(&b), 2);
+
+        let b = b.into_rc_slice();
+        assert_eq!(b.len(), 2);
+        drop((b, c));
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let a: Arc<[_]> = Arc::new([1, 2, 3]);
+        let b = SliceN::<_, 3>::try_from_arc(a).unwrap();
+        assert_eq!(b.head, [1, 2, 3]);
+        assert!(b.tail.is_empty());
+        assert_eq!(*b.into_arc_slice(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn slice_n_cow() {
+        let a: &[_] = &[1, 2, 3];
+        let mut b: Cow<'_, SliceN<_, 2>> = Cow::Borrowed(a.try_into().unwrap());
+        assert_eq!(b.head, [1, 2]);
+
+        b.to_mut().head = [0, 0];
+        assert!(matches!(b, Cow::Owned(_)));
+        assert_eq!(b.head, [0, 0]);
+        assert_eq!(b.tail, [3]);
+        assert_eq!(a, [1, 2, 3]);
+    }
+
+    #[test]
+    fn slice_n_concat_boxed() {
+        let a: &[_] = &[vec![1, 2], vec![3]];
+        let b: &SliceN<_, 1> = a.try_into().unwrap();
+        assert_eq!(b.concat(), [1, 2, 3]);
+
+        let c = b.concat_boxed(b);
+        assert_eq!(c.head, [vec![1, 2], vec![3]]);
+        assert_eq!(c.tail, [vec![1, 2], vec![3]]);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::SliceN;
+use core::cell::Cell;
+
+/// View a cell containing an array as an array of cells
+///
+/// ```
+/// use core::cell::Cell;
+/// let a = Cell::new([1_i32, 2, 3]);
+/// let b = cl_array_ext::as_array_of_cells(&a);
+/// b[0].set(b[2].get());
+/// assert_eq!(a.get(), [3, 2, 3]);
+/// ```
+pub fn as_array_of_cells<T, const N: usize>(cell: &Cell<[T; N]>) -> &[Cell<T>; N] {
+    // SAFETY: Cell is repr(transparent), so Cell<[T; N]> and [Cell<T>; N] have the same layout,
+    // and the shared cell already allows its contents to be mutated through `&`
+    unsafe { &*cell.as_ptr().cast() }
+}
+
+/// View an array of cells as a cell containing an array
+///
+/// ```
+/// use core::cell::Cell;
+/// let a = [Cell::new(1_i32), Cell::new(2), Cell::new(3)];
+/// let b = cl_array_ext::as_cell_of_array(&a);
+/// b.set([4, 5, 6]);
+/// assert_eq!(a[1].get(), 5);
+/// ```
+pub fn as_cell_of_array<T, const N: usize>(cells: &[Cell<T>; N]) -> &Cell<[T; N]> {
+    // SAFETY: Cell is repr(transparent), so [Cell<T>; N] and Cell<[T; N]> have the same layout,
+    // and every element already allows being mutated through `&`
+    unsafe { &*cells.as_ptr().cast() }
+}
+
+/// View a cell containing a [`SliceN`] as a [`SliceN`] of cells
+///
+/// ```
+/// use cl_array_ext::SliceN;
+/// use core::cell::Cell;
+/// let a: &mut [_] = &mut [1_i32, 2, 3];
+/// let b: &mut SliceN<_, 2> = a.try_into().unwrap();
+/// let c = cl_array_ext::as_slice_n_of_cells(Cell::from_mut(b));
+/// c.head[0].set(c.tail[0].get());
+/// assert_eq!(a, [3, 2, 3]);
+/// ```
+pub fn as_slice_n_of_cells<T, const N: usize>(cell: &Cell<SliceN<T, N>>) -> &SliceN<Cell<T>, N> {
+    // SAFETY: Cell is repr(transparent), so the Cell<SliceN<T, N>> pointer carries the same
+    // metadata (the tail length) as the SliceN inside. SliceN is repr(C) with the head followed by
+    // the tail, and Cell<T> has the same size and alignment as T, so SliceN<Cell<T>, N> with that
+    // same metadata covers exactly the same elements at the same offsets
+    let (p, meta) = core::ptr::from_ref(cell).to_raw_parts();
+    unsafe { &*core::ptr::from_raw_parts(p, meta) }
+}
+
+/// View a [`SliceN`] of cells as a cell containing a [`SliceN`]
+///
+/// ```
+/// use cl_array_ext::SliceN;
+/// use core::cell::Cell;
+/// let a: &[_] = &[Cell::new(1_i32), Cell::new(2), Cell::new(3)];
+/// let b: &SliceN<_, 2> = a.try_into().unwrap();
+/// let c = cl_array_ext::as_cell_of_slice_n(b);
+/// assert!(core::ptr::eq(cl_array_ext::as_slice_n_of_cells(c), b));
+/// ```
+pub fn as_cell_of_slice_n<T, const N: usize>(cells: &SliceN<Cell<T>, N>) -> &Cell<SliceN<T, N>> {
+    // SAFETY: SliceN is repr(C) with the head followed by the tail, and Cell<T> has the same size
+    // and alignment as T, so SliceN<T, N> with the same metadata (the tail length) covers exactly
+    // the same elements. Cell is repr(transparent), so wrapping it in a Cell keeps that metadata,
+    // and every element already allows being mutated through `&`
+    let (p, meta) = core::ptr::from_ref(cells).to_raw_parts();
+    unsafe { &*core::ptr::from_raw_parts(p, meta) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SliceN;
+    use core::cell::Cell;
+
+    #[test]
+    fn cell_projection() {
+        let a = Cell::new([1, 2, 3]);
+        let b = crate::as_array_of_cells(&a);
+        let c = crate::as_cell_of_array(b);
+
+        b[1].set(0);
+        assert_eq!(c.get(), [1, 0, 3]);
+        c.set([4, 5, 6]);
+        assert_eq!(b[0].get(), 4);
+        assert_eq!(a.get(), [4, 5, 6]);
+    }
+
+    #[test]
+    fn cell_projection_slice_n() {
+        let a: &mut [_] = &mut [1, 2, 3, 4];
+        let b: &mut SliceN<_, 3> = a.try_into().unwrap();
+        let c = crate::as_slice_n_of_cells(Cell::from_mut(b));
+        let d = crate::as_slice_n_of_cells(crate::as_cell_of_slice_n(c));
+
+        c.head[1].set(0);
+        d.tail[0].set(d.head[1].get() + 5);
+        assert_eq!(c.head.len(), 3);
+        assert_eq!(a, [1, 0, 3, 5]);
+    }
+}
@@ -0,0 +1,197 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+/// An array that is initialised one element at a time.
+/// If dropped early, only the elements that have been initialised so far are dropped
+pub(crate) struct Guard<'a, T, const N: usize> {
+    arr: &'a mut [MaybeUninit<T>; N],
+    init: usize,
+}
+
+impl<'a, T, const N: usize> Guard<'a, T, N> {
+    pub(crate) fn new(arr: &'a mut [MaybeUninit<T>; N]) -> Self {
+        Self { arr, init: 0 }
+    }
+
+    /// The most recently initialised element
+    pub(crate) fn last(&self) -> Option<&T> {
+        let i = self.init.checked_sub(1)?;
+        unsafe { Some(self.arr.get_unchecked(i).assume_init_ref()) }
+    }
+
+    /// # Safety
+    /// There must be fewer than N elements initialised
+    pub(crate) unsafe fn push_unchecked(&mut self, value: T) {
+        self.arr.get_unchecked_mut(self.init).write(value);
+        self.init += 1;
+    }
+
+    /// # Safety
+    /// All N elements must be initialised
+    pub(crate) unsafe fn finish_unchecked(self) -> &'a mut [T; N] {
+        let mut this = ManuallyDrop::new(self);
+        &mut *this.arr.as_mut_ptr().cast()
+    }
+}
+
+impl<T, const N: usize> Drop for Guard<'_, T, N> {
+    fn drop(&mut self) {
+        let p = self.arr.as_mut_ptr().cast::<T>();
+        unsafe { core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(p, self.init)) }
+    }
+}
+
+/// Create an array of uninitialised elements
+///
+/// ```
+/// use cl_array_ext::{array_assume_init, uninit_array};
+/// let mut a = uninit_array::<i32, 3>();
+/// a[0].write(1);
+/// a[1].write(2);
+/// a[2].write(3);
+/// assert_eq!(unsafe { array_assume_init(a) }, [1, 2, 3]);
+/// ```
+#[must_use]
+pub const fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
+    [const { MaybeUninit::uninit() }; N]
+}
+
+/// Extract the values out of an array of initialised elements
+/// # Safety
+/// Every element of the array must be initialised, otherwise this will result in UB
+pub const unsafe fn array_assume_init<T, const N: usize>(arr: [MaybeUninit<T>; N]) -> [T; N] {
+    let arr = ManuallyDrop::new(arr);
+    core::ptr::read(core::ptr::from_ref(&arr).cast())
+}
+
+/// Create an array where every element is [`Default::default`]. Works for any N and any `T: Default`
+///
+/// ```
+/// let a: [String; 40] = cl_array_ext::default_array();
+/// assert!(a.iter().all(String::is_empty));
+/// ```
+#[must_use]
+pub fn default_array<T: Default, const N: usize>() -> [T; N] {
+    core::array::from_fn(|_| T::default())
+}
+
+/// Initialise every element of the array in place with the result of calling `f` with its index,
+/// returning a reference to the now initialised array.
+///
+/// If `f` panics, any elements already initialised are dropped.
+/// Otherwise, like [`MaybeUninit::write`], the initialised elements are not dropped automatically
+///
+/// ```
+/// use cl_array_ext::{array_assume_init, init_with, uninit_array};
+/// let mut a = uninit_array::<usize, 3>();
+/// let b = init_with(&mut a, |i| i * 2);
+/// b[0] = 1;
+/// assert_eq!(unsafe { array_assume_init(a) }, [1, 2, 4]);
+/// ```
+pub fn init_with<T, const N: usize>(
+    arr: &mut [MaybeUninit<T>; N],
+    mut f: impl FnMut(usize) -> T,
+) -> &mut [T; N] {
+    let mut guard = Guard::new(arr);
+    while guard.init < N {
+        let value = f(guard.init);
+        unsafe { guard.push_unchecked(value) };
+    }
+    unsafe { guard.finish_unchecked() }
+}
+
+/// Create an array where each element is the result of calling `f` with its index,
+/// returning early with the first error
+///
+/// ```
+/// let a = cl_array_ext::try_from_fn(|i| u8::try_from(i * 100));
+/// assert_eq!(a, Ok([0, 100, 200]));
+/// let a: Result<[u8; 4], _> = cl_array_ext::try_from_fn(|i| u8::try_from(i * 100));
+/// assert!(a.is_err());
+/// ```
+/// # Errors
+/// Returns the first error produced by `f`. Any elements already created are dropped
+pub fn try_from_fn<T, E, const N: usize>(
+    mut f: impl FnMut(usize) -> Result<T, E>,
+) -> Result<[T; N], E> {
+    let mut arr = uninit_array();
+    let mut guard = Guard::new(&mut arr);
+    while guard.init < N {
+        let value = f(guard.init)?;
+        unsafe { guard.push_unchecked(value) };
+    }
+    unsafe {
+        guard.finish_unchecked();
+        Ok(array_assume_init(arr))
+    }
+}
+
+/// Error type returned by [`from_iter_exact`]
+#[derive(Debug, PartialEq, Eq)]
+pub enum FromIterError {
+    /// The iterator ended early, after producing this many items
+    TooFew(usize),
+    /// The iterator produced more items than would fit in the array
+    TooMany,
+}
+
+/// Create an array from an iterator that should produce exactly N items
+///
+/// ```
+/// use cl_array_ext::{from_iter_exact, FromIterError};
+/// let a = from_iter_exact(1..=3);
+/// assert_eq!(a, Ok([1, 2, 3]));
+/// let a: Result<[i32; 3], _> = from_iter_exact(1..=2);
+/// assert_eq!(a, Err(FromIterError::TooFew(2)));
+/// let a: Result<[i32; 3], _> = from_iter_exact(1..=4);
+/// assert_eq!(a, Err(FromIterError::TooMany));
+/// ```
+/// # Errors
+/// Returns an error if the iterator produces fewer or more than N items. Any items already taken are dropped
+pub fn from_iter_exact<T, I: IntoIterator<Item = T>, const N: usize>(
+    iter: I,
+) -> Result<[T; N], FromIterError> {
+    let mut iter = iter.into_iter();
+    let arr = try_from_fn(|i| iter.next().ok_or(FromIterError::TooFew(i)))?;
+    match iter.next() {
+        Some(_) => Err(FromIterError::TooMany),
+        None => Ok(arr),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    #[test]
+    fn try_from_fn() {
+        let rc = Rc::new(());
+        let a: Result<[_; 4], _> =
+            crate::try_from_fn(|i| if i == 2 { Err(i) } else { Ok(rc.clone()) });
+        assert_eq!(a, Err(2));
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn from_iter_exact() {
+        let rc = Rc::new(());
+        let a: Result<[_; 4], _> = crate::from_iter_exact(core::iter::repeat_n(rc.clone(), 3));
+        assert_eq!(a, Err(crate::FromIterError::TooFew(3)));
+        let a: Result<[_; 2], _> = crate::from_iter_exact(core::iter::repeat_n(rc.clone(), 3));
+        assert_eq!(a, Err(crate::FromIterError::TooMany));
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn init_with() {
+        let rc = Rc::new(());
+        let mut a = crate::uninit_array::<_, 4>();
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            crate::init_with(&mut a, |i| {
+                assert!(i < 2);
+                rc.clone()
+            });
+        }));
+        assert!(res.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -5,9 +5,46 @@
 #![feature(generic_const_exprs, ptr_metadata)]
 #![warn(clippy::pedantic, missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
+
+mod array_vec;
+pub use array_vec::*;
+
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "alloc")]
+pub use boxed::*;
+
+mod cell;
+pub use cell::*;
+
+mod init;
+pub use init::*;
+
+mod nested;
+pub use nested::*;
+
+mod pair;
+pub use pair::*;
+
+mod refs;
+pub use refs::*;
+
+mod sequence;
+pub use sequence::*;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
 mod slice;
 pub use slice::*;
 
+mod slice_last;
+pub use slice_last::*;
+
 /// Trait that extends upon [array]
 pub trait ArrayExt<T, const N: usize>: Sized {
     /// Split an array into two smaller arrays
@@ -44,52 +81,1918 @@ pub trait ArrayExt<T, const N: usize>: Sized {
     /// assert_eq!(a, [1, 2, 3, 4, 5]);
     /// ```
     fn append<const M: usize>(self, other: [T; M]) -> [T; N + M];
-}
 
-impl<T, const N: usize> ArrayExt<T, N> for [T; N] {
-    fn array_split_at<const M: usize>(self) -> ([T; M], [T; N - M])
+    /// Add a single value onto the end of the array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3].push(4);
+    /// assert_eq!(a, [1, 2, 3, 4]);
+    /// ```
+    #[doc(alias = "push_back")]
+    fn push(self, value: T) -> [T; N + 1] {
+        self.append([value])
+    }
+
+    /// Add a single value onto the start of the array, shifting the rest along
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [2_i32, 3, 4].push_front(1);
+    /// assert_eq!(a, [1, 2, 3, 4]);
+    /// ```
+    fn push_front(self, value: T) -> [T; N + 1];
+
+    /// Remove the last value from the array, returning it alongside the rest
+    ///
+    /// This is the owned equivalent of [`slice::split_last`]
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let (a, b) = [1_i32, 2, 3, 4].pop_back();
+    /// assert_eq!(a, [1, 2, 3]);
+    /// assert_eq!(b, 4);
+    /// ```
+    #[doc(alias = "split_last")]
+    fn pop_back(self) -> ([T; N - 1], T)
+    where
+        [T; N - 1]: Sized; // N >= 1
+
+    /// Remove the first value from the array, returning it alongside the rest
+    ///
+    /// This is the owned equivalent of [`slice::split_first`]
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let (a, b) = [1_i32, 2, 3, 4].pop_front();
+    /// assert_eq!(a, 1);
+    /// assert_eq!(b, [2, 3, 4]);
+    /// ```
+    #[doc(alias = "split_first")]
+    fn pop_front(self) -> (T, [T; N - 1])
+    where
+        [T; N - 1]: Sized; // N >= 1
+
+    /// Insert a value into the array at index I, shifting everything after it along
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 4, 5].insert_at::<2>(3);
+    /// assert_eq!(a, [1, 2, 3, 4, 5]);
+    /// ```
+    fn insert_at<const I: usize>(self, value: T) -> [T; N + 1]
+    where
+        [T; N - I]: Sized; // I <= N
+
+    /// Remove the value at index I from the array, returning it alongside the rest
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let (a, b) = [1_i32, 2, 3, 4].remove_at::<1>();
+    /// assert_eq!(a, 2);
+    /// assert_eq!(b, [1, 3, 4]);
+    /// ```
+    fn remove_at<const I: usize>(self) -> (T, [T; N - 1])
+    where
+        [T; N - I - 1]: Sized; // I < N
+
+    /// Join two arrays into one larger array, with `other` placed before `self`
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [4_i32, 5].prepend([1, 2, 3]);
+    /// assert_eq!(a, [1, 2, 3, 4, 5]);
+    /// ```
+    fn prepend<const M: usize>(self, other: [T; M]) -> [T; M + N];
+
+    /// Split an array into two smaller arrays, measuring M elements from the end
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let (a, b) = [1_i32, 2, 3, 4, 5].array_rsplit_at::<3>();
+    /// assert_eq!(a, [1, 2]);
+    /// assert_eq!(b, [3, 4, 5]);
+    /// ```
+    #[doc(alias = "rsplit_at")]
+    fn array_rsplit_at<const M: usize>(self) -> ([T; N - M], [T; M])
+    where
+        [T; N - M]: Sized;
+
+    /// Drop the first M elements out of the array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1, 2, 3, 4, 5].skip::<3>();
+    /// assert_eq!(a, [4, 5]);
+    /// ```
+    #[doc(alias = "truncate_front")]
+    fn skip<const M: usize>(self) -> [T; N - M]
     where
         [T; N - M]: Sized,
     {
-        let arr = core::mem::ManuallyDrop::new(self);
-        unsafe {
-            (
-                core::ptr::read(arr.as_ptr().add(0).cast()),
-                core::ptr::read(arr.as_ptr().add(M).cast()),
-            )
-        }
+        self.array_split_at::<M>().1
     }
 
-    fn append<const M: usize>(self, other: [T; M]) -> [T; N + M] {
-        let arr_a = core::mem::ManuallyDrop::new(self);
-        let arr_b = core::mem::ManuallyDrop::new(other);
-        let mut arr_c = core::mem::MaybeUninit::<[T; N + M]>::uninit();
-        let p = arr_c.as_mut_ptr().cast::<T>();
+    /// Split an array into three smaller arrays
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let (a, b, c) = [1_i32, 2, 3, 4, 5, 6].split_at3::<1, 3>();
+    /// assert_eq!(a, [1]);
+    /// assert_eq!(b, [2, 3, 4]);
+    /// assert_eq!(c, [5, 6]);
+    /// ```
+    fn split_at3<const A: usize, const B: usize>(self) -> ([T; A], [T; B], [T; N - A - B])
+    where
+        [T; N - A - B]: Sized; // A + B <= N
 
-        unsafe {
-            core::ptr::copy(arr_a.as_ptr(), p.add(0), N);
-            core::ptr::copy(arr_b.as_ptr(), p.add(N), M);
+    /// Split an array into an array of equally sized chunks
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4, 5, 6].into_chunks::<2>();
+    /// assert_eq!(a, [[1, 2], [3, 4], [5, 6]]);
+    /// ```
+    fn into_chunks<const M: usize>(self) -> [[T; M]; N / M]
+    where
+        [T; 0 - N % M]: Sized; // N % M == 0
 
-            core::mem::MaybeUninit::assume_init(arr_c)
-        }
+    /// Split an array into an array of equally sized chunks, plus whatever is left over
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let (a, b) = [1_i32, 2, 3, 4, 5, 6, 7].into_chunks_rem::<3>();
+    /// assert_eq!(a, [[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(b, [7]);
+    /// ```
+    fn into_chunks_rem<const M: usize>(self) -> ([[T; M]; N / M], [T; N % M]);
+
+    /// Split a flat array back into an array of rows. The inverse of [`NestedArrayExt::flatten`]
+    ///
+    /// ```
+    /// use cl_array_ext::{ArrayExt, NestedArrayExt};
+    /// let a = [1_i32, 2, 3, 4, 5, 6].unflatten::<3>();
+    /// assert_eq!(a, [[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a.flatten(), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn unflatten<const M: usize>(self) -> [[T; M]; N / M]
+    where
+        [T; 0 - N % M]: Sized, // N % M == 0
+    {
+        self.into_chunks()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::ArrayExt;
+    /// Join two arrays of the same length into one array of pairs
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3].zip(['a', 'b', 'c']);
+    /// assert_eq!(a, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// ```
+    fn zip<U>(self, other: [U; N]) -> [(T, U); N];
 
-    #[test]
-    fn split_at() {
-        let (a, b) = [1, 2, 3, 4, 5].array_split_at::<3>();
-        assert_eq!(a, [1, 2, 3]);
-        assert_eq!(b, [4, 5]);
+    /// Combine two arrays of the same length element by element
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3].zip_with([10, 20, 30], |a, b| a + b);
+    /// assert_eq!(a, [11, 22, 33]);
+    /// ```
+    fn zip_with<U, V>(self, other: [U; N], f: impl FnMut(T, U) -> V) -> [V; N];
+
+    /// Map each element of the array, along with its index, into a new array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = ['a', 'b', 'c'].map_with_index(|i, c| (i, c));
+    /// assert_eq!(a, [(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// ```
+    fn map_with_index<U>(self, f: impl FnMut(usize, T) -> U) -> [U; N];
+
+    /// Reverse the order of the elements in the array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3].reversed().append([0]);
+    /// assert_eq!(a, [3, 2, 1, 0]);
+    /// ```
+    fn reversed(self) -> [T; N];
+
+    /// Rotate the array K places to the left
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4, 5].rotated_left::<2>();
+    /// assert_eq!(a, [3, 4, 5, 1, 2]);
+    /// ```
+    fn rotated_left<const K: usize>(self) -> [T; N]
+    where
+        [T; N - K]: Sized; // K <= N
+
+    /// Rotate the array K places to the right
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4, 5].rotated_right::<2>();
+    /// assert_eq!(a, [4, 5, 1, 2, 3]);
+    /// ```
+    fn rotated_right<const K: usize>(self) -> [T; N]
+    where
+        [T; N - K]: Sized; // K <= N
+
+    /// Join two arrays of the same length by alternating between their elements
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 3, 5].interleave([2, 4, 6]);
+    /// assert_eq!(a, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn interleave(self, other: [T; N]) -> [T; 2 * N];
+
+    /// Split an array into its even and odd positioned elements. The inverse of [`ArrayExt::interleave`]
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let (a, b) = [1_i32, 2, 3, 4, 5, 6].deinterleave();
+    /// assert_eq!(a, [1, 3, 5]);
+    /// assert_eq!(b, [2, 4, 6]);
+    /// ```
+    fn deinterleave(self) -> ([T; N / 2], [T; N / 2])
+    where
+        [T; 0 - N % 2]: Sized; // N % 2 == 0
+
+    /// Repeat the contents of the array M times
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2].array_repeat::<3>();
+    /// assert_eq!(a, [1, 2, 1, 2, 1, 2]);
+    /// ```
+    #[doc(alias = "repeat")]
+    fn array_repeat<const M: usize>(self) -> [T; N * M]
+    where
+        T: Clone;
+
+    /// Resize the array to M elements, either truncating it or padding the end with `fill`
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3].resize::<5>(0);
+    /// assert_eq!(a, [1, 2, 3, 0, 0]);
+    /// let a = [1_i32, 2, 3].resize::<2>(0);
+    /// assert_eq!(a, [1, 2]);
+    /// ```
+    fn resize<const M: usize>(self, fill: T) -> [T; M]
+    where
+        T: Clone;
+
+    /// Extend the array by M elements, generating each new element from its index
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_usize, 2].extend_with::<3>(|i| i * 10);
+    /// assert_eq!(a, [1, 2, 20, 30, 40]);
+    /// ```
+    fn extend_with<const M: usize>(self, mut f: impl FnMut(usize) -> T) -> [T; N + M] {
+        self.append(core::array::from_fn(|i| f(N + i)))
     }
 
-    #[test]
-    fn append() {
-        let a = [1, 2, 3].append([4, 5]);
+    /// Map each element of the array with a fallible function, stopping at the first error
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = ["1", "2", "3"].array_try_map(str::parse::<i32>);
+    /// assert_eq!(a, Ok([1, 2, 3]));
+    /// let a = ["1", "two", "3"].array_try_map(str::parse::<i32>);
+    /// assert!(a.is_err());
+    /// ```
+    /// # Errors
+    /// Returns the first error produced by `f`
+    fn array_try_map<U, E>(self, f: impl FnMut(T) -> Result<U, E>) -> Result<[U; N], E>;
 
-        assert_eq!(a, [1, 2, 3, 4, 5]);
+    /// Fold over the array, collecting every intermediate state into a new array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4].scan(0, |acc, x| acc + x);
+    /// assert_eq!(a, [1, 3, 6, 10]);
+    /// ```
+    fn scan<U>(self, init: U, f: impl FnMut(&U, T) -> U) -> [U; N];
+
+    /// Sort the array
+    ///
+    /// This sort is stable, but uses insertion sort so is best suited to small arrays
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [3_i32, 1, 4, 1, 5].sorted().truncate::<3>();
+    /// assert_eq!(a, [1, 1, 3]);
+    /// ```
+    fn sorted(self) -> [T; N]
+    where
+        T: Ord;
+
+    /// Sort the array with a comparator function
+    ///
+    /// This sort is stable, but uses insertion sort so is best suited to small arrays
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [3_i32, 1, 4, 1, 5].sorted_by(|a, b| b.cmp(a));
+    /// assert_eq!(a, [5, 4, 3, 1, 1]);
+    /// ```
+    fn sorted_by(self, compare: impl FnMut(&T, &T) -> core::cmp::Ordering) -> [T; N];
+
+    /// Sort the array with a key extraction function
+    ///
+    /// This sort is stable, but uses insertion sort so is best suited to small arrays
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [-3_i32, 1, -4, 2].sorted_by_key(|x| x.abs());
+    /// assert_eq!(a, [1, 2, -3, -4]);
+    /// ```
+    fn sorted_by_key<K: Ord>(self, f: impl FnMut(&T) -> K) -> [T; N];
+
+    /// Get the smallest element out of a non-empty array
+    ///
+    /// If several elements are equally minimum, the first element is returned
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [3_i32, 1, 4].array_min();
+    /// assert_eq!(a, 1);
+    /// ```
+    #[doc(alias = "min")]
+    fn array_min(self) -> T
+    where
+        T: Ord,
+        [T; N - 1]: Sized; // N >= 1
+
+    /// Get the largest element out of a non-empty array
+    ///
+    /// If several elements are equally maximum, the last element is returned
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [3_i32, 1, 4].array_max();
+    /// assert_eq!(a, 4);
+    /// ```
+    #[doc(alias = "max")]
+    fn array_max(self) -> T
+    where
+        T: Ord,
+        [T; N - 1]: Sized; // N >= 1
+
+    /// Get the element that gives the smallest key out of a non-empty array
+    ///
+    /// If several elements are equally minimum, the first element is returned
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [-3_i32, 1, -4].array_min_by_key(|x| x.abs());
+    /// assert_eq!(a, 1);
+    /// ```
+    #[doc(alias = "min_by_key")]
+    fn array_min_by_key<K: Ord>(self, f: impl FnMut(&T) -> K) -> T
+    where
+        [T; N - 1]: Sized; // N >= 1
+
+    /// Get the element that gives the largest key out of a non-empty array
+    ///
+    /// If several elements are equally maximum, the last element is returned
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [-3_i32, 1, -4].array_max_by_key(|x| x.abs());
+    /// assert_eq!(a, -4);
+    /// ```
+    #[doc(alias = "max_by_key")]
+    fn array_max_by_key<K: Ord>(self, f: impl FnMut(&T) -> K) -> T
+    where
+        [T; N - 1]: Sized; // N >= 1
+
+    /// Swap the elements at indices I and J
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3, 4];
+    /// a.swap_const::<0, 3>();
+    /// assert_eq!(a, [4, 2, 3, 1]);
+    /// ```
+    fn swap_const<const I: usize, const J: usize>(&mut self)
+    where
+        [T; N - I - 1]: Sized, // I < N
+        [T; N - J - 1]: Sized; // J < N
+
+    /// Create a new array by copying out the elements at each of the given indices
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [10_i32, 20, 30, 40].gather([3, 0, 0]);
+    /// assert_eq!(a, [40, 10, 10]);
+    /// ```
+    /// # Panics
+    /// Panics if any of the indices are out of bounds
+    fn gather<const M: usize>(&self, indices: [usize; M]) -> [T; M]
+    where
+        T: Copy;
+
+    /// Create a new array by copying out the elements at each of the given indices
+    /// # Safety
+    /// All of the indices must be less than N, otherwise this will result in UB
+    unsafe fn gather_unchecked<const M: usize>(&self, indices: [usize; M]) -> [T; M]
+    where
+        T: Copy;
+
+    /// Clone every overlapping window of length M into an array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4].cloned_windows::<2>();
+    /// assert_eq!(a, [[1, 2], [2, 3], [3, 4]]);
+    /// ```
+    #[doc(alias = "windows")]
+    fn cloned_windows<const M: usize>(&self) -> [[T; M]; N - M + 1]
+    where
+        T: Clone,
+        [T; M - 1]: Sized, // M >= 1
+        [T; N - M]: Sized; // M <= N
+
+    /// Clone every pair of adjacent elements into an array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 3, 6, 10].pairwise().map(|(a, b)| b - a);
+    /// assert_eq!(a, [2, 3, 4]);
+    /// ```
+    fn pairwise(&self) -> [(T, T); N - 1]
+    where
+        T: Clone,
+        [T; N - 1]: Sized; // N >= 1
+
+    /// Place a copy of `sep` between each pair of adjacent elements
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3].intersperse(0);
+    /// assert_eq!(a, [1, 0, 2, 0, 3]);
+    /// ```
+    fn intersperse(self, sep: T) -> [T; 2 * N - 1]
+    where
+        T: Clone,
+        [T; N - 1]: Sized; // N >= 1
+
+    /// Move only the elements that match the predicate into a new [`ArrayVec`]
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4, 5].retain_into(|x| x % 2 == 1);
+    /// assert_eq!(a, [1, 3, 5]);
+    /// ```
+    fn retain_into(self, pred: impl FnMut(&T) -> bool) -> ArrayVec<T, N>;
+
+    /// Move each element into one of two [`ArrayVec`]s, depending on whether it matches the predicate
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let (a, b) = [1_i32, 2, 3, 4, 5].partition(|x| x % 2 == 1);
+    /// assert_eq!(a, [1, 3, 5]);
+    /// assert_eq!(b, [2, 4]);
+    /// ```
+    fn partition(self, pred: impl FnMut(&T) -> bool) -> (ArrayVec<T, N>, ArrayVec<T, N>);
+
+    /// Add a value onto the end of the array, shifting the first value out to keep the same length
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let (a, b) = [1_i32, 2, 3].shift_in(4);
+    /// assert_eq!(a, 1);
+    /// assert_eq!(b, [2, 3, 4]);
+    /// ```
+    fn shift_in(self, value: T) -> (T, [T; N])
+    where
+        [T; N - 1]: Sized; // N >= 1
+
+    /// Swap the LEN elements starting at index A with the LEN elements starting at index B.
+    /// The chunk at A must end before the chunk at B starts
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3, 4, 5, 6];
+    /// a.swap_chunks::<0, 3, 2>();
+    /// assert_eq!(a, [4, 5, 3, 1, 2, 6]);
+    /// ```
+    fn swap_chunks<const A: usize, const B: usize, const LEN: usize>(&mut self)
+    where
+        [T; B - A - LEN]: Sized, // A + LEN <= B
+        [T; N - B - LEN]: Sized; // B + LEN <= N
+
+    /// Move the elements into a new [`ArrayVec`], skipping any that are equal to the element before them
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 1, 2, 3, 3, 3, 1].dedup();
+    /// assert_eq!(a, [1, 2, 3, 1]);
+    /// ```
+    #[doc(alias = "dedup_consecutive")]
+    fn dedup(self) -> ArrayVec<T, N>
+    where
+        T: PartialEq;
+
+    /// Split an array reference into two smaller array references
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4, 5];
+    /// let (b, c) = a.split_at_ref::<3>();
+    /// assert_eq!(b, &[1, 2, 3]);
+    /// assert_eq!(c, &[4, 5]);
+    /// ```
+    fn split_at_ref<const M: usize>(&self) -> (&[T; M], &[T; N - M])
+    where
+        [T; N - M]: Sized;
+
+    /// Split a mutable array reference into two smaller mutable array references
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3, 4, 5];
+    /// let (b, c) = a.split_at_mut_ref::<3>();
+    /// b.reverse();
+    /// c[0] = 0;
+    /// assert_eq!(a, [3, 2, 1, 0, 5]);
+    /// ```
+    fn split_at_mut_ref<const M: usize>(&mut self) -> (&mut [T; M], &mut [T; N - M])
+    where
+        [T; N - M]: Sized;
+
+    /// View an array reference as a reference to an array of equally sized chunks
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4, 5, 6];
+    /// assert_eq!(a.as_chunks_ref::<2>(), &[[1, 2], [3, 4], [5, 6]]);
+    /// ```
+    fn as_chunks_ref<const M: usize>(&self) -> &[[T; M]; N / M]
+    where
+        [T; 0 - N % M]: Sized; // N % M == 0
+
+    /// View a mutable array reference as a mutable reference to an array of equally sized chunks
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3, 4, 5, 6];
+    /// for chunk in a.as_chunks_mut_ref::<3>() {
+    ///     chunk.reverse();
+    /// }
+    /// assert_eq!(a, [3, 2, 1, 6, 5, 4]);
+    /// ```
+    #[doc(alias = "as_chunks_mut")]
+    fn as_chunks_mut_ref<const M: usize>(&mut self) -> &mut [[T; M]; N / M]
+    where
+        [T; 0 - N % M]: Sized; // N % M == 0
+
+    /// Get a reference to the first M elements of the array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4, 5];
+    /// assert_eq!(a.first_chunk_ref::<2>(), &[1, 2]);
+    /// ```
+    fn first_chunk_ref<const M: usize>(&self) -> &[T; M]
+    where
+        [T; N - M]: Sized; // M <= N
+
+    /// Get a reference to the last M elements of the array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4, 5];
+    /// assert_eq!(a.last_chunk_ref::<2>(), &[4, 5]);
+    /// ```
+    fn last_chunk_ref<const M: usize>(&self) -> &[T; M]
+    where
+        [T; N - M]: Sized; // M <= N
+
+    /// Get a mutable reference to the first M elements of the array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3, 4, 5];
+    /// *a.first_chunk_mut_ref::<2>() = [0, 0];
+    /// assert_eq!(a, [0, 0, 3, 4, 5]);
+    /// ```
+    fn first_chunk_mut_ref<const M: usize>(&mut self) -> &mut [T; M]
+    where
+        [T; N - M]: Sized; // M <= N
+
+    /// Get a mutable reference to the last M elements of the array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3, 4, 5];
+    /// *a.last_chunk_mut_ref::<2>() = [0, 0];
+    /// assert_eq!(a, [1, 2, 3, 0, 0]);
+    /// ```
+    fn last_chunk_mut_ref<const M: usize>(&mut self) -> &mut [T; M]
+    where
+        [T; N - M]: Sized; // M <= N
+
+    /// Get a reference to the LEN elements starting at index OFFSET
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [1_i32, 2, 3, 4, 5];
+    /// assert_eq!(a.array_ref::<1, 3>(), &[2, 3, 4]);
+    /// ```
+    fn array_ref<const OFFSET: usize, const LEN: usize>(&self) -> &[T; LEN]
+    where
+        [T; N - OFFSET - LEN]: Sized; // OFFSET + LEN <= N
+
+    /// Get a mutable reference to the LEN elements starting at index OFFSET
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3, 4, 5];
+    /// *a.array_mut::<1, 3>() = [0, 0, 0];
+    /// assert_eq!(a, [1, 0, 0, 0, 5]);
+    /// ```
+    fn array_mut<const OFFSET: usize, const LEN: usize>(&mut self) -> &mut [T; LEN]
+    where
+        [T; N - OFFSET - LEN]: Sized; // OFFSET + LEN <= N
+
+    /// Copy a smaller array into the start of this array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [0_u8; 5];
+    /// a.copy_from_prefix(&[1, 2]);
+    /// assert_eq!(a, [1, 2, 0, 0, 0]);
+    /// ```
+    fn copy_from_prefix<const M: usize>(&mut self, src: &[T; M])
+    where
+        T: Copy,
+        [T; N - M]: Sized, // M <= N
+    {
+        *self.first_chunk_mut_ref() = *src;
+    }
+
+    /// Copy a smaller array into the end of this array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [0_u8; 5];
+    /// a.copy_from_suffix(&[1, 2]);
+    /// assert_eq!(a, [0, 0, 0, 1, 2]);
+    /// ```
+    fn copy_from_suffix<const M: usize>(&mut self, src: &[T; M])
+    where
+        T: Copy,
+        [T; N - M]: Sized, // M <= N
+    {
+        *self.last_chunk_mut_ref() = *src;
+    }
+
+    /// Get mutable references to two disjoint sub-arrays at once: the `A_LEN` elements starting at A,
+    /// and the `B_LEN` elements starting at B. The sub-array at A must end before the one at B starts
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [0_i32; 6];
+    /// let (b, c) = a.disjoint_mut2::<0, 2, 3, 3>();
+    /// *b = [1, 2];
+    /// *c = [3, 4, 5];
+    /// assert_eq!(a, [1, 2, 0, 3, 4, 5]);
+    /// ```
+    fn disjoint_mut2<const A: usize, const A_LEN: usize, const B: usize, const B_LEN: usize>(
+        &mut self,
+    ) -> (&mut [T; A_LEN], &mut [T; B_LEN])
+    where
+        [T; B - A - A_LEN]: Sized, // A + A_LEN <= B
+        [T; N - B - B_LEN]: Sized; // B + B_LEN <= N
+
+    /// Get mutable references to three disjoint sub-arrays at once: the `A_LEN` elements starting at A,
+    /// the `B_LEN` elements starting at B and the `C_LEN` elements starting at C.
+    /// Each sub-array must end before the next one starts
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [0_i32; 6];
+    /// let (b, c, d) = a.disjoint_mut3::<0, 1, 2, 2, 5, 1>();
+    /// *b = [1];
+    /// *c = [2, 3];
+    /// *d = [4];
+    /// assert_eq!(a, [1, 0, 2, 3, 0, 4]);
+    /// ```
+    fn disjoint_mut3<
+        const A: usize,
+        const A_LEN: usize,
+        const B: usize,
+        const B_LEN: usize,
+        const C: usize,
+        const C_LEN: usize,
+    >(
+        &mut self,
+    ) -> (&mut [T; A_LEN], &mut [T; B_LEN], &mut [T; C_LEN])
+    where
+        [T; B - A - A_LEN]: Sized, // A + A_LEN <= B
+        [T; C - B - B_LEN]: Sized, // B + B_LEN <= C
+        [T; N - C - C_LEN]: Sized; // C + C_LEN <= N
+
+    /// Overwrite every element of the array with the result of calling `f` with its index
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [0_usize; 4];
+    /// a.fill_from_fn(|i| i * 2);
+    /// assert_eq!(a, [0, 2, 4, 6]);
+    /// ```
+    fn fill_from_fn(&mut self, f: impl FnMut(usize) -> T);
+
+    /// Swap the LEN elements starting at index A of this array with the LEN elements
+    /// starting at index B of `other`
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3, 4];
+    /// let mut b = [5_i32, 6, 7];
+    /// a.swap_range_with::<2, 1, 0, _>(&mut b);
+    /// assert_eq!(a, [1, 5, 6, 4]);
+    /// assert_eq!(b, [2, 3, 7]);
+    /// ```
+    fn swap_range_with<const LEN: usize, const A: usize, const B: usize, const M: usize>(
+        &mut self,
+        other: &mut [T; M],
+    ) where
+        [T; N - A - LEN]: Sized, // A + LEN <= N
+        [T; M - B - LEN]: Sized; // B + LEN <= M
+
+    /// Rotate the array K places to the left, in place
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3, 4, 5];
+    /// a.rotate_left_in_place::<2>();
+    /// assert_eq!(a, [3, 4, 5, 1, 2]);
+    /// ```
+    fn rotate_left_in_place<const K: usize>(&mut self)
+    where
+        [T; N - K]: Sized; // K <= N
+
+    /// Rotate the array K places to the right, in place
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3, 4, 5];
+    /// a.rotate_right_in_place::<2>();
+    /// assert_eq!(a, [4, 5, 1, 2, 3]);
+    /// ```
+    fn rotate_right_in_place<const K: usize>(&mut self)
+    where
+        [T; N - K]: Sized; // K <= N
+
+    /// Copy the first N elements out of a slice, returning them alongside the rest of the slice
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let data: &[u8] = &[1, 2, 3, 4, 5];
+    /// let (a, rest) = <[u8; 2]>::try_from_prefix(data).unwrap();
+    /// assert_eq!(a, [1, 2]);
+    /// assert_eq!(rest, [3, 4, 5]);
+    /// assert!(<[u8; 6]>::try_from_prefix(data).is_err());
+    /// ```
+    /// # Errors
+    /// There should be at least N elements in the slice, otherwise this will return an error
+    fn try_from_prefix(slice: &[T]) -> Result<([T; N], &[T]), NotEnoughEntries>
+    where
+        T: Copy,
+    {
+        let slice: &SliceN<T, N> = slice.try_into()?;
+        Ok((slice.head, &slice.tail))
+    }
+
+    /// Map a reference to each element of the array into a new array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let a = [String::from("a"), String::from("bb")];
+    /// assert_eq!(a.map_ref(String::len), [1, 2]);
+    /// ```
+    fn map_ref<U>(&self, f: impl FnMut(&T) -> U) -> [U; N];
+
+    /// Map a mutable reference to each element of the array into a new array
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let mut a = [1_i32, 2, 3];
+    /// let b = a.map_mut(|x| core::mem::replace(x, 0) * 2);
+    /// assert_eq!(a, [0, 0, 0]);
+    /// assert_eq!(b, [2, 4, 6]);
+    /// ```
+    fn map_mut<U>(&mut self, f: impl FnMut(&mut T) -> U) -> [U; N];
+
+    /// Map each element of the array to [`Default::default`], giving a new array of the same length.
+    /// Works for any N and any `U: Default`, see [`default_array`]
+    ///
+    /// ```
+    /// use cl_array_ext::ArrayExt;
+    /// let names = ["a", "b", "c"];
+    /// let mut counts: [Vec<usize>; 3] = names.map_default();
+    /// counts[1].push(1);
+    /// assert_eq!(counts, [vec![], vec![1], vec![]]);
+    /// ```
+    fn map_default<U: Default>(&self) -> [U; N] {
+        default_array()
+    }
+}
+
+impl<T, const N: usize> ArrayExt<T, N> for [T; N] {
+    fn array_split_at<const M: usize>(self) -> ([T; M], [T; N - M])
+    where
+        [T; N - M]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        unsafe {
+            (
+                core::ptr::read(arr.as_ptr().add(0).cast()),
+                core::ptr::read(arr.as_ptr().add(M).cast()),
+            )
+        }
+    }
+
+    fn append<const M: usize>(self, other: [T; M]) -> [T; N + M] {
+        let arr_a = core::mem::ManuallyDrop::new(self);
+        let arr_b = core::mem::ManuallyDrop::new(other);
+        let mut arr_c = core::mem::MaybeUninit::<[T; N + M]>::uninit();
+        let p = arr_c.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            core::ptr::copy(arr_a.as_ptr(), p.add(0), N);
+            core::ptr::copy(arr_b.as_ptr(), p.add(N), M);
+
+            core::mem::MaybeUninit::assume_init(arr_c)
+        }
+    }
+
+    fn push_front(self, value: T) -> [T; N + 1] {
+        let arr = core::mem::ManuallyDrop::new(self);
+        let mut arr_c = core::mem::MaybeUninit::<[T; N + 1]>::uninit();
+        let p = arr_c.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            core::ptr::write(p, value);
+            core::ptr::copy(arr.as_ptr(), p.add(1), N);
+
+            core::mem::MaybeUninit::assume_init(arr_c)
+        }
+    }
+
+    fn pop_back(self) -> ([T; N - 1], T)
+    where
+        [T; N - 1]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        unsafe {
+            (
+                core::ptr::read(arr.as_ptr().add(0).cast()),
+                core::ptr::read(arr.as_ptr().add(N - 1)),
+            )
+        }
+    }
+
+    fn pop_front(self) -> (T, [T; N - 1])
+    where
+        [T; N - 1]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        unsafe {
+            (
+                core::ptr::read(arr.as_ptr().add(0)),
+                core::ptr::read(arr.as_ptr().add(1).cast()),
+            )
+        }
+    }
+
+    fn insert_at<const I: usize>(self, value: T) -> [T; N + 1]
+    where
+        [T; N - I]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        let mut arr_c = core::mem::MaybeUninit::<[T; N + 1]>::uninit();
+        let p = arr_c.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            core::ptr::copy(arr.as_ptr(), p.add(0), I);
+            core::ptr::write(p.add(I), value);
+            core::ptr::copy(arr.as_ptr().add(I), p.add(I + 1), N - I);
+
+            core::mem::MaybeUninit::assume_init(arr_c)
+        }
+    }
+
+    fn remove_at<const I: usize>(self) -> (T, [T; N - 1])
+    where
+        [T; N - I - 1]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        let mut arr_c = core::mem::MaybeUninit::<[T; N - 1]>::uninit();
+        let p = arr_c.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            core::ptr::copy(arr.as_ptr(), p.add(0), I);
+            core::ptr::copy(arr.as_ptr().add(I + 1), p.add(I), N - I - 1);
+
+            (
+                core::ptr::read(arr.as_ptr().add(I)),
+                core::mem::MaybeUninit::assume_init(arr_c),
+            )
+        }
+    }
+
+    fn prepend<const M: usize>(self, other: [T; M]) -> [T; M + N] {
+        other.append(self)
+    }
+
+    fn array_rsplit_at<const M: usize>(self) -> ([T; N - M], [T; M])
+    where
+        [T; N - M]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        unsafe {
+            (
+                core::ptr::read(arr.as_ptr().add(0).cast()),
+                core::ptr::read(arr.as_ptr().add(N - M).cast()),
+            )
+        }
+    }
+
+    fn split_at3<const A: usize, const B: usize>(self) -> ([T; A], [T; B], [T; N - A - B])
+    where
+        [T; N - A - B]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        unsafe {
+            (
+                core::ptr::read(arr.as_ptr().add(0).cast()),
+                core::ptr::read(arr.as_ptr().add(A).cast()),
+                core::ptr::read(arr.as_ptr().add(A + B).cast()),
+            )
+        }
+    }
+
+    fn into_chunks<const M: usize>(self) -> [[T; M]; N / M]
+    where
+        [T; 0 - N % M]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        unsafe { core::ptr::read(arr.as_ptr().cast()) }
+    }
+
+    fn into_chunks_rem<const M: usize>(self) -> ([[T; M]; N / M], [T; N % M]) {
+        let arr = core::mem::ManuallyDrop::new(self);
+        unsafe {
+            (
+                core::ptr::read(arr.as_ptr().add(0).cast()),
+                core::ptr::read(arr.as_ptr().add(N / M * M).cast()),
+            )
+        }
+    }
+
+    fn zip<U>(self, other: [U; N]) -> [(T, U); N] {
+        let arr_a = core::mem::ManuallyDrop::new(self);
+        let arr_b = core::mem::ManuallyDrop::new(other);
+        let mut arr_c = core::mem::MaybeUninit::<[(T, U); N]>::uninit();
+        let p = arr_c.as_mut_ptr().cast::<(T, U)>();
+
+        unsafe {
+            for i in 0..N {
+                let a = core::ptr::read(arr_a.as_ptr().add(i));
+                let b = core::ptr::read(arr_b.as_ptr().add(i));
+                core::ptr::write(p.add(i), (a, b));
+            }
+
+            core::mem::MaybeUninit::assume_init(arr_c)
+        }
+    }
+
+    fn zip_with<U, V>(self, other: [U; N], mut f: impl FnMut(T, U) -> V) -> [V; N] {
+        let mut other = other.into_iter();
+        // other has exactly as many elements as self, so next can never fail
+        self.map(|a| f(a, unsafe { other.next().unwrap_unchecked() }))
+    }
+
+    fn map_with_index<U>(self, mut f: impl FnMut(usize, T) -> U) -> [U; N] {
+        let mut i = 0;
+        self.map(|x| {
+            let u = f(i, x);
+            i += 1;
+            u
+        })
+    }
+
+    fn reversed(mut self) -> [T; N] {
+        self.reverse();
+        self
+    }
+
+    fn rotated_left<const K: usize>(self) -> [T; N]
+    where
+        [T; N - K]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        let mut arr_c = core::mem::MaybeUninit::<[T; N]>::uninit();
+        let p = arr_c.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            core::ptr::copy(arr.as_ptr().add(K), p.add(0), N - K);
+            core::ptr::copy(arr.as_ptr(), p.add(N - K), K);
+
+            core::mem::MaybeUninit::assume_init(arr_c)
+        }
+    }
+
+    fn rotated_right<const K: usize>(self) -> [T; N]
+    where
+        [T; N - K]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        let mut arr_c = core::mem::MaybeUninit::<[T; N]>::uninit();
+        let p = arr_c.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            core::ptr::copy(arr.as_ptr().add(N - K), p.add(0), K);
+            core::ptr::copy(arr.as_ptr(), p.add(K), N - K);
+
+            core::mem::MaybeUninit::assume_init(arr_c)
+        }
+    }
+
+    fn interleave(self, other: [T; N]) -> [T; 2 * N] {
+        let arr_a = core::mem::ManuallyDrop::new(self);
+        let arr_b = core::mem::ManuallyDrop::new(other);
+        let mut arr_c = core::mem::MaybeUninit::<[T; 2 * N]>::uninit();
+        let p = arr_c.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            for i in 0..N {
+                core::ptr::copy(arr_a.as_ptr().add(i), p.add(2 * i), 1);
+                core::ptr::copy(arr_b.as_ptr().add(i), p.add(2 * i + 1), 1);
+            }
+
+            core::mem::MaybeUninit::assume_init(arr_c)
+        }
+    }
+
+    fn deinterleave(self) -> ([T; N / 2], [T; N / 2])
+    where
+        [T; 0 - N % 2]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        let mut arr_a = core::mem::MaybeUninit::<[T; N / 2]>::uninit();
+        let mut arr_b = core::mem::MaybeUninit::<[T; N / 2]>::uninit();
+        let pa = arr_a.as_mut_ptr().cast::<T>();
+        let pb = arr_b.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            for i in 0..N / 2 {
+                core::ptr::copy(arr.as_ptr().add(2 * i), pa.add(i), 1);
+                core::ptr::copy(arr.as_ptr().add(2 * i + 1), pb.add(i), 1);
+            }
+
+            (
+                core::mem::MaybeUninit::assume_init(arr_a),
+                core::mem::MaybeUninit::assume_init(arr_b),
+            )
+        }
+    }
+
+    fn array_repeat<const M: usize>(self) -> [T; N * M]
+    where
+        T: Clone,
+    {
+        core::array::from_fn(|i| self[i % N].clone())
+    }
+
+    fn resize<const M: usize>(self, fill: T) -> [T; M]
+    where
+        T: Clone,
+    {
+        let mut iter = self.into_iter();
+        core::array::from_fn(|_| iter.next().unwrap_or_else(|| fill.clone()))
+    }
+
+    fn array_try_map<U, E>(self, mut f: impl FnMut(T) -> Result<U, E>) -> Result<[U; N], E> {
+        let mut iter = self.into_iter();
+        // iter has exactly N elements, so next can never fail
+        init::try_from_fn(|_| f(unsafe { iter.next().unwrap_unchecked() }))
+    }
+
+    fn scan<U>(self, init: U, mut f: impl FnMut(&U, T) -> U) -> [U; N] {
+        let mut arr = init::uninit_array();
+        let mut guard = init::Guard::new(&mut arr);
+        for x in self {
+            let u = f(guard.last().unwrap_or(&init), x);
+            unsafe { guard.push_unchecked(u) };
+        }
+        unsafe {
+            guard.finish_unchecked();
+            init::array_assume_init(arr)
+        }
+    }
+
+    fn sorted(mut self) -> [T; N]
+    where
+        T: Ord,
+    {
+        insertion_sort_by(&mut self, T::lt);
+        self
+    }
+
+    fn sorted_by(mut self, mut compare: impl FnMut(&T, &T) -> core::cmp::Ordering) -> [T; N] {
+        insertion_sort_by(&mut self, |a, b| compare(a, b).is_lt());
+        self
+    }
+
+    fn sorted_by_key<K: Ord>(mut self, mut f: impl FnMut(&T) -> K) -> [T; N] {
+        insertion_sort_by(&mut self, |a, b| f(a) < f(b));
+        self
+    }
+
+    fn array_min(self) -> T
+    where
+        T: Ord,
+        [T; N - 1]: Sized,
+    {
+        // N >= 1 so there is always a minimum
+        unsafe { self.into_iter().min().unwrap_unchecked() }
+    }
+
+    fn array_max(self) -> T
+    where
+        T: Ord,
+        [T; N - 1]: Sized,
+    {
+        // N >= 1 so there is always a maximum
+        unsafe { self.into_iter().max().unwrap_unchecked() }
+    }
+
+    fn array_min_by_key<K: Ord>(self, f: impl FnMut(&T) -> K) -> T
+    where
+        [T; N - 1]: Sized,
+    {
+        // N >= 1 so there is always a minimum
+        unsafe { self.into_iter().min_by_key(f).unwrap_unchecked() }
+    }
+
+    fn array_max_by_key<K: Ord>(self, f: impl FnMut(&T) -> K) -> T
+    where
+        [T; N - 1]: Sized,
+    {
+        // N >= 1 so there is always a maximum
+        unsafe { self.into_iter().max_by_key(f).unwrap_unchecked() }
+    }
+
+    fn swap_const<const I: usize, const J: usize>(&mut self)
+    where
+        [T; N - I - 1]: Sized,
+        [T; N - J - 1]: Sized,
+    {
+        let p = self.as_mut_ptr();
+        unsafe { core::ptr::swap(p.add(I), p.add(J)) }
+    }
+
+    fn gather<const M: usize>(&self, indices: [usize; M]) -> [T; M]
+    where
+        T: Copy,
+    {
+        indices.map(|i| self[i])
+    }
+
+    unsafe fn gather_unchecked<const M: usize>(&self, indices: [usize; M]) -> [T; M]
+    where
+        T: Copy,
+    {
+        indices.map(|i| *self.get_unchecked(i))
+    }
+
+    fn cloned_windows<const M: usize>(&self) -> [[T; M]; N - M + 1]
+    where
+        T: Clone,
+        [T; M - 1]: Sized,
+        [T; N - M]: Sized,
+    {
+        core::array::from_fn(|i| core::array::from_fn(|j| self[i + j].clone()))
+    }
+
+    fn pairwise(&self) -> [(T, T); N - 1]
+    where
+        T: Clone,
+        [T; N - 1]: Sized,
+    {
+        core::array::from_fn(|i| (self[i].clone(), self[i + 1].clone()))
+    }
+
+    fn intersperse(self, sep: T) -> [T; 2 * N - 1]
+    where
+        T: Clone,
+        [T; N - 1]: Sized,
+    {
+        let mut iter = self.into_iter();
+        core::array::from_fn(|i| {
+            if i % 2 == 0 {
+                // there are exactly N even indices, so next can never fail
+                unsafe { iter.next().unwrap_unchecked() }
+            } else {
+                sep.clone()
+            }
+        })
+    }
+
+    fn retain_into(self, mut pred: impl FnMut(&T) -> bool) -> ArrayVec<T, N> {
+        let mut out = ArrayVec::new();
+        for x in self {
+            if pred(&x) {
+                // there are only N elements, so out can never be full
+                unsafe { out.push_unchecked(x) };
+            }
+        }
+        out
+    }
+
+    fn partition(self, mut pred: impl FnMut(&T) -> bool) -> (ArrayVec<T, N>, ArrayVec<T, N>) {
+        let mut a = ArrayVec::new();
+        let mut b = ArrayVec::new();
+        for x in self {
+            // there are only N elements, so neither can ever be full
+            if pred(&x) {
+                unsafe { a.push_unchecked(x) };
+            } else {
+                unsafe { b.push_unchecked(x) };
+            }
+        }
+        (a, b)
+    }
+
+    fn shift_in(self, value: T) -> (T, [T; N])
+    where
+        [T; N - 1]: Sized,
+    {
+        let arr = core::mem::ManuallyDrop::new(self);
+        let mut arr_c = core::mem::MaybeUninit::<[T; N]>::uninit();
+        let p = arr_c.as_mut_ptr().cast::<T>();
+
+        unsafe {
+            core::ptr::copy(arr.as_ptr().add(1), p.add(0), N - 1);
+            core::ptr::write(p.add(N - 1), value);
+
+            (
+                core::ptr::read(arr.as_ptr().add(0)),
+                core::mem::MaybeUninit::assume_init(arr_c),
+            )
+        }
+    }
+
+    fn swap_chunks<const A: usize, const B: usize, const LEN: usize>(&mut self)
+    where
+        [T; B - A - LEN]: Sized,
+        [T; N - B - LEN]: Sized,
+    {
+        let p = self.as_mut_ptr();
+        unsafe { core::ptr::swap_nonoverlapping(p.add(A), p.add(B), LEN) }
+    }
+
+    fn dedup(self) -> ArrayVec<T, N>
+    where
+        T: PartialEq,
+    {
+        let mut out = ArrayVec::<T, N>::new();
+        for x in self {
+            if out.last() != Some(&x) {
+                // there are only N elements, so out can never be full
+                unsafe { out.push_unchecked(x) };
+            }
+        }
+        out
+    }
+
+    fn split_at_ref<const M: usize>(&self) -> (&[T; M], &[T; N - M])
+    where
+        [T; N - M]: Sized,
+    {
+        let p = self.as_ptr();
+        unsafe { (&*p.add(0).cast(), &*p.add(M).cast()) }
+    }
+
+    fn split_at_mut_ref<const M: usize>(&mut self) -> (&mut [T; M], &mut [T; N - M])
+    where
+        [T; N - M]: Sized,
+    {
+        let p = self.as_mut_ptr();
+        unsafe { (&mut *p.add(0).cast(), &mut *p.add(M).cast()) }
+    }
+
+    fn as_chunks_ref<const M: usize>(&self) -> &[[T; M]; N / M]
+    where
+        [T; 0 - N % M]: Sized,
+    {
+        unsafe { &*self.as_ptr().cast() }
+    }
+
+    fn as_chunks_mut_ref<const M: usize>(&mut self) -> &mut [[T; M]; N / M]
+    where
+        [T; 0 - N % M]: Sized,
+    {
+        unsafe { &mut *self.as_mut_ptr().cast() }
+    }
+
+    fn first_chunk_ref<const M: usize>(&self) -> &[T; M]
+    where
+        [T; N - M]: Sized,
+    {
+        unsafe { &*self.as_ptr().add(0).cast() }
+    }
+
+    fn last_chunk_ref<const M: usize>(&self) -> &[T; M]
+    where
+        [T; N - M]: Sized,
+    {
+        unsafe { &*self.as_ptr().add(N - M).cast() }
+    }
+
+    fn first_chunk_mut_ref<const M: usize>(&mut self) -> &mut [T; M]
+    where
+        [T; N - M]: Sized,
+    {
+        unsafe { &mut *self.as_mut_ptr().add(0).cast() }
+    }
+
+    fn last_chunk_mut_ref<const M: usize>(&mut self) -> &mut [T; M]
+    where
+        [T; N - M]: Sized,
+    {
+        unsafe { &mut *self.as_mut_ptr().add(N - M).cast() }
+    }
+
+    fn array_ref<const OFFSET: usize, const LEN: usize>(&self) -> &[T; LEN]
+    where
+        [T; N - OFFSET - LEN]: Sized,
+    {
+        unsafe { &*self.as_ptr().add(OFFSET).cast() }
+    }
+
+    fn array_mut<const OFFSET: usize, const LEN: usize>(&mut self) -> &mut [T; LEN]
+    where
+        [T; N - OFFSET - LEN]: Sized,
+    {
+        unsafe { &mut *self.as_mut_ptr().add(OFFSET).cast() }
+    }
+
+    fn disjoint_mut2<const A: usize, const A_LEN: usize, const B: usize, const B_LEN: usize>(
+        &mut self,
+    ) -> (&mut [T; A_LEN], &mut [T; B_LEN])
+    where
+        [T; B - A - A_LEN]: Sized,
+        [T; N - B - B_LEN]: Sized,
+    {
+        let p = self.as_mut_ptr();
+        unsafe { (&mut *p.add(A).cast(), &mut *p.add(B).cast()) }
+    }
+
+    fn disjoint_mut3<
+        const A: usize,
+        const A_LEN: usize,
+        const B: usize,
+        const B_LEN: usize,
+        const C: usize,
+        const C_LEN: usize,
+    >(
+        &mut self,
+    ) -> (&mut [T; A_LEN], &mut [T; B_LEN], &mut [T; C_LEN])
+    where
+        [T; B - A - A_LEN]: Sized,
+        [T; C - B - B_LEN]: Sized,
+        [T; N - C - C_LEN]: Sized,
+    {
+        let p = self.as_mut_ptr();
+        unsafe {
+            (
+                &mut *p.add(A).cast(),
+                &mut *p.add(B).cast(),
+                &mut *p.add(C).cast(),
+            )
+        }
+    }
+
+    fn fill_from_fn(&mut self, mut f: impl FnMut(usize) -> T) {
+        for (i, x) in self.iter_mut().enumerate() {
+            *x = f(i);
+        }
+    }
+
+    fn swap_range_with<const LEN: usize, const A: usize, const B: usize, const M: usize>(
+        &mut self,
+        other: &mut [T; M],
+    ) where
+        [T; N - A - LEN]: Sized,
+        [T; M - B - LEN]: Sized,
+    {
+        unsafe {
+            core::ptr::swap_nonoverlapping(
+                self.as_mut_ptr().add(A),
+                other.as_mut_ptr().add(B),
+                LEN,
+            );
+        }
+    }
+
+    fn rotate_left_in_place<const K: usize>(&mut self)
+    where
+        [T; N - K]: Sized,
+    {
+        // reverse both halves, then the whole array
+        let (a, b) = self.split_at_mut_ref::<K>();
+        a.reverse();
+        b.reverse();
+        self.reverse();
+    }
+
+    fn rotate_right_in_place<const K: usize>(&mut self)
+    where
+        [T; N - K]: Sized,
+    {
+        self.reverse();
+        let (a, b) = self.split_at_mut_ref::<K>();
+        a.reverse();
+        b.reverse();
+    }
+
+    fn map_ref<U>(&self, mut f: impl FnMut(&T) -> U) -> [U; N] {
+        core::array::from_fn(|i| f(&self[i]))
+    }
+
+    fn map_mut<U>(&mut self, mut f: impl FnMut(&mut T) -> U) -> [U; N] {
+        core::array::from_fn(|i| f(&mut self[i]))
+    }
+}
+
+/// Stable in-place sort, where `is_less` returns whether the first argument should come before the second
+fn insertion_sort_by<T>(v: &mut [T], mut is_less: impl FnMut(&T, &T) -> bool) {
+    for i in 1..v.len() {
+        let mut j = i;
+        while j > 0 && is_less(&v[j], &v[j - 1]) {
+            v.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ArrayExt;
+    use std::rc::Rc;
+    use std::string::{String, ToString};
+
+    #[test]
+    fn split_at() {
+        let (a, b) = [1, 2, 3, 4, 5].array_split_at::<3>();
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(b, [4, 5]);
+    }
+
+    #[test]
+    fn append() {
+        let a = [1, 2, 3].append([4, 5]);
+
+        assert_eq!(a, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn push() {
+        let a = [String::from("a"), String::from("b")].push(String::from("c"));
+
+        assert_eq!(a, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn push_front() {
+        let a = [String::from("b"), String::from("c")].push_front(String::from("a"));
+
+        assert_eq!(a, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn pop_back() {
+        let (a, b) = [String::from("a"), String::from("b"), String::from("c")].pop_back();
+
+        assert_eq!(a, ["a", "b"]);
+        assert_eq!(b, "c");
+    }
+
+    #[test]
+    fn pop_front() {
+        let (a, b) = [String::from("a"), String::from("b"), String::from("c")].pop_front();
+
+        assert_eq!(a, "a");
+        assert_eq!(b, ["b", "c"]);
+    }
+
+    #[test]
+    fn insert_at() {
+        let a = [1, 2, 3].insert_at::<0>(0);
+        assert_eq!(a, [0, 1, 2, 3]);
+
+        let a = [1, 2, 3].insert_at::<3>(4);
+        assert_eq!(a, [1, 2, 3, 4]);
+
+        let a = [String::from("a"), String::from("c")].insert_at::<1>(String::from("b"));
+        assert_eq!(a, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn remove_at() {
+        let (a, b) = [1, 2, 3].remove_at::<0>();
+        assert_eq!(a, 1);
+        assert_eq!(b, [2, 3]);
+
+        let (a, b) = [1, 2, 3].remove_at::<2>();
+        assert_eq!(a, 3);
+        assert_eq!(b, [1, 2]);
+
+        let (a, b) = [String::from("a"), String::from("b"), String::from("c")].remove_at::<1>();
+        assert_eq!(a, "b");
+        assert_eq!(b, ["a", "c"]);
+    }
+
+    #[test]
+    fn prepend() {
+        let a = [4, 5].prepend([1, 2, 3]);
+
+        assert_eq!(a, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn rsplit_at() {
+        let (a, b) = [1, 2, 3, 4, 5].array_rsplit_at::<2>();
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(b, [4, 5]);
+    }
+
+    #[test]
+    fn skip() {
+        let a = [String::from("a"), String::from("b"), String::from("c")].skip::<2>();
+
+        assert_eq!(a, ["c"]);
+    }
+
+    #[test]
+    fn split_at3() {
+        let (a, b, c) = [1, 2, 3, 4, 5].split_at3::<2, 3>();
+        assert_eq!(a, [1, 2]);
+        assert_eq!(b, [3, 4, 5]);
+        assert_eq!(c, []);
+    }
+
+    #[test]
+    fn into_chunks() {
+        let a = [1, 2, 3, 4, 5, 6].into_chunks::<3>();
+        assert_eq!(a, [[1, 2, 3], [4, 5, 6]]);
+
+        let a = [0_u8; 0].into_chunks::<4>();
+        assert_eq!(a, [[0_u8; 4]; 0]);
+    }
+
+    #[test]
+    fn into_chunks_rem() {
+        let (a, b) = [1, 2, 3, 4, 5, 6].into_chunks_rem::<3>();
+        assert_eq!(a, [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(b, []);
+
+        let (a, b) =
+            [String::from("a"), String::from("b"), String::from("c")].into_chunks_rem::<2>();
+        assert_eq!(a, [["a", "b"]]);
+        assert_eq!(b, ["c"]);
+    }
+
+    #[test]
+    fn zip() {
+        let a = [String::from("a"), String::from("b")].zip([1, 2]);
+
+        assert_eq!(a, [(String::from("a"), 1), (String::from("b"), 2)]);
+    }
+
+    #[test]
+    fn zip_with() {
+        let a = [String::from("a"), String::from("b")].zip_with(["c", "d"], |a, b| a + b);
+
+        assert_eq!(a, ["ac", "bd"]);
+    }
+
+    #[test]
+    fn map_with_index() {
+        let a = [String::from("a"), String::from("b")].map_with_index(|i, s| s.repeat(i + 1));
+
+        assert_eq!(a, ["a", "bb"]);
+    }
+
+    #[test]
+    fn reversed() {
+        let a = [String::from("a"), String::from("b"), String::from("c")].reversed();
+
+        assert_eq!(a, ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn rotated() {
+        let a = [1, 2, 3];
+        assert_eq!(a.rotated_left::<0>(), [1, 2, 3]);
+        assert_eq!(a.rotated_left::<3>(), [1, 2, 3]);
+        assert_eq!(a.rotated_right::<1>(), [3, 1, 2]);
+
+        let a = [String::from("a"), String::from("b"), String::from("c")].rotated_left::<1>();
+        assert_eq!(a, ["b", "c", "a"]);
+    }
+
+    #[test]
+    fn interleave() {
+        let a = [String::from("a"), String::from("c")];
+        let b = [String::from("b"), String::from("d")];
+
+        assert_eq!(a.interleave(b), ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn deinterleave() {
+        let a = [String::from("a"), String::from("c")];
+        let b = [String::from("b"), String::from("d")];
+        let (a, b) = a.interleave(b).deinterleave();
+
+        assert_eq!(a, ["a", "c"]);
+        assert_eq!(b, ["b", "d"]);
+    }
+
+    #[test]
+    fn array_repeat() {
+        let a = [String::from("a"), String::from("b")].array_repeat::<2>();
+        assert_eq!(a, ["a", "b", "a", "b"]);
+
+        let a = [0_u8; 0].array_repeat::<2>();
+        assert_eq!(a, []);
+    }
+
+    #[test]
+    fn resize() {
+        let a = [String::from("a"), String::from("b")];
+        assert_eq!(a.clone().resize::<1>(String::new()), ["a"]);
+        assert_eq!(a.resize::<3>(String::from("c")), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn extend_with() {
+        let a = [String::from("a")].extend_with::<2>(|i| i.to_string());
+
+        assert_eq!(a, ["a", "1", "2"]);
+    }
+
+    #[test]
+    fn array_try_map() {
+        let a = [String::from("1"), String::from("2")].array_try_map(|s| s.parse::<i32>());
+        assert_eq!(a, Ok([1, 2]));
+
+        let rc = Rc::new(());
+        let a = [0, 1, 2, 3].array_try_map(|i| if i == 2 { Err(i) } else { Ok(rc.clone()) });
+        assert_eq!(a, Err(2));
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn scan() {
+        let a =
+            [String::from("a"), String::from("b")].scan(String::new(), |acc, x| acc.clone() + &x);
+
+        assert_eq!(a, ["a", "ab"]);
+    }
+
+    #[test]
+    fn sorted() {
+        let a = [String::from("b"), String::from("c"), String::from("a")].sorted();
+        assert_eq!(a, ["a", "b", "c"]);
+
+        assert_eq!([0_u8; 0].sorted(), []);
+    }
+
+    #[test]
+    fn sorted_by() {
+        let a = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+        assert_eq!(
+            a.sorted_by(|a, b| a.0.cmp(&b.0)),
+            [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]
+        );
+        assert_eq!(
+            a.sorted_by_key(|a| a.0),
+            [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]
+        );
+    }
+
+    #[test]
+    fn array_min_max() {
+        let a = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+        assert_eq!(a.array_min(), (0, 'b'));
+        assert_eq!(a.array_max(), (1, 'c'));
+        assert_eq!(a.array_min_by_key(|a| a.0), (0, 'b'));
+        assert_eq!(a.array_max_by_key(|a| a.0), (1, 'c'));
+
+        assert_eq!([String::from("a")].array_min(), "a");
+    }
+
+    #[test]
+    fn swap_const() {
+        let mut a = [String::from("a"), String::from("b"), String::from("c")];
+        a.swap_const::<0, 2>();
+        assert_eq!(a, ["c", "b", "a"]);
+        a.swap_const::<1, 1>();
+        assert_eq!(a, ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn gather() {
+        let a = [1, 2, 3];
+        assert_eq!(a.gather([2, 1, 0, 1]), [3, 2, 1, 2]);
+        assert_eq!(unsafe { a.gather_unchecked([0, 2]) }, [1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn gather_out_of_bounds() {
+        let _ = [1, 2, 3].gather([3]);
+    }
+
+    #[test]
+    fn cloned_windows() {
+        let a = [String::from("a"), String::from("b"), String::from("c")];
+        assert_eq!(a.cloned_windows::<3>(), [["a", "b", "c"]]);
+        assert_eq!(a.cloned_windows::<2>(), [["a", "b"], ["b", "c"]]);
+    }
+
+    #[test]
+    fn pairwise() {
+        let a = [String::from("a"), String::from("b"), String::from("c")];
+        assert_eq!(
+            a.pairwise(),
+            [
+                (String::from("a"), String::from("b")),
+                (String::from("b"), String::from("c"))
+            ]
+        );
+        assert_eq!([1].pairwise(), []);
+    }
+
+    #[test]
+    fn intersperse() {
+        let a = [String::from("a"), String::from("b")].intersperse(String::from(","));
+        assert_eq!(a, ["a", ",", "b"]);
+
+        assert_eq!([1].intersperse(0), [1]);
+    }
+
+    #[test]
+    fn retain_into() {
+        let a = [String::from("a"), String::from("bb"), String::from("c")];
+        let a = a.retain_into(|s| s.len() == 1);
+
+        assert_eq!(a, ["a", "c"]);
+    }
+
+    #[test]
+    fn partition() {
+        let a = [String::from("a"), String::from("bb"), String::from("c")];
+        let (a, b) = a.partition(|s| s.len() == 1);
+
+        assert_eq!(a, ["a", "c"]);
+        assert_eq!(b, ["bb"]);
+    }
+
+    #[test]
+    fn shift_in() {
+        let (a, b) = [String::from("a"), String::from("b")].shift_in(String::from("c"));
+        assert_eq!(a, "a");
+        assert_eq!(b, ["b", "c"]);
+
+        assert_eq!([1].shift_in(2), (1, [2]));
+    }
+
+    #[test]
+    fn swap_chunks() {
+        let mut a = [1, 2, 3, 4];
+        a.swap_chunks::<0, 2, 2>();
+        assert_eq!(a, [3, 4, 1, 2]);
+
+        let mut a = [String::from("a"), String::from("b"), String::from("c")];
+        a.swap_chunks::<0, 2, 1>();
+        assert_eq!(a, ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn dedup() {
+        let a = [String::from("a"), String::from("a"), String::from("b")].dedup();
+        assert_eq!(a, ["a", "b"]);
+
+        assert!([0_u8; 0].dedup().is_empty());
+    }
+
+    #[test]
+    fn split_at_ref() {
+        let a = [1, 2, 3, 4, 5];
+        let (b, c) = a.split_at_ref::<5>();
+        assert_eq!(b, &a);
+        assert_eq!(c, &[]);
+    }
+
+    #[test]
+    fn split_at_mut_ref() {
+        let mut a = [String::from("a"), String::from("b"), String::from("c")];
+        let (b, c) = a.split_at_mut_ref::<1>();
+        core::mem::swap(&mut b[0], &mut c[1]);
+        assert_eq!(a, ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn as_chunks_ref() {
+        let a = [1, 2, 3, 4, 5, 6];
+        assert_eq!(a.as_chunks_ref::<3>(), &[[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(a.as_chunks_ref::<6>(), &[a]);
+    }
+
+    #[test]
+    fn as_chunks_mut_ref() {
+        let mut a = [1, 2, 3, 4];
+        a.as_chunks_mut_ref::<2>().swap(0, 1);
+        assert_eq!(a, [3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn chunk_ref() {
+        let mut a = [1, 2, 3];
+        assert_eq!(a.first_chunk_ref::<0>(), &[]);
+        assert_eq!(a.last_chunk_ref::<3>(), &[1, 2, 3]);
+
+        a.first_chunk_mut_ref::<2>().reverse();
+        a.last_chunk_mut_ref::<2>().reverse();
+        assert_eq!(a, [2, 3, 1]);
+    }
+
+    #[test]
+    fn array_ref() {
+        let mut a = [1, 2, 3, 4];
+        assert_eq!(a.array_ref::<0, 4>(), &[1, 2, 3, 4]);
+        assert_eq!(a.array_ref::<4, 0>(), &[]);
+
+        a.array_mut::<2, 2>().reverse();
+        assert_eq!(a, [1, 2, 4, 3]);
+    }
+
+    #[test]
+    fn disjoint_mut() {
+        let mut a = [1, 2, 3, 4];
+        let (b, c) = a.disjoint_mut2::<0, 2, 2, 2>();
+        core::mem::swap(b, c);
+        assert_eq!(a, [3, 4, 1, 2]);
+
+        let (b, c, d) = a.disjoint_mut3::<0, 1, 1, 0, 3, 1>();
+        core::mem::swap(b, d);
+        assert_eq!(c, &[]);
+        assert_eq!(a, [2, 4, 1, 3]);
+    }
+
+    #[test]
+    fn fill_from_fn() {
+        let mut a = [String::new(), String::new()];
+        a.fill_from_fn(|i| i.to_string());
+        assert_eq!(a, ["0", "1"]);
+    }
+
+    #[test]
+    fn swap_range_with() {
+        let mut a = [String::from("a"), String::from("b")];
+        let mut b = [String::from("c")];
+        a.swap_range_with::<1, 1, 0, 1>(&mut b);
+        assert_eq!(a, ["a", "c"]);
+        assert_eq!(b, ["b"]);
+    }
+
+    #[test]
+    fn rotate_in_place() {
+        let mut a = [1, 2, 3];
+        a.rotate_left_in_place::<0>();
+        assert_eq!(a, [1, 2, 3]);
+        a.rotate_left_in_place::<3>();
+        assert_eq!(a, [1, 2, 3]);
+        a.rotate_right_in_place::<1>();
+        assert_eq!(a, [3, 1, 2]);
+        assert_eq!(a.rotated_left::<1>(), [1, 2, 3]);
+
+        let mut a = [String::from("a"), String::from("b"), String::from("c")];
+        a.rotate_left_in_place::<1>();
+        assert_eq!(a, ["b", "c", "a"]);
     }
 }
@@ -0,0 +1,226 @@
+/// Trait that extends upon arrays of arrays
+pub trait NestedArrayExt<T, const M: usize, const N: usize>: Sized {
+    /// Join the inner arrays into one large array
+    ///
+    /// ```
+    /// use cl_array_ext::NestedArrayExt;
+    /// let a = [[1_i32, 2], [3, 4], [5, 6]].flatten();
+    /// assert_eq!(a, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn flatten(self) -> [T; N * M];
+
+    /// Swap the rows and columns of the nested array
+    ///
+    /// ```
+    /// use cl_array_ext::NestedArrayExt;
+    /// let a = [[1_i32, 2, 3], [4, 5, 6]].transpose();
+    /// assert_eq!(a, [[1, 4], [2, 5], [3, 6]]);
+    /// ```
+    fn transpose(self) -> [[T; N]; M];
+
+    /// Iterate over references to each row of the nested array
+    ///
+    /// ```
+    /// use cl_array_ext::NestedArrayExt;
+    /// let a = [[1_i32, 2], [3, 4]];
+    /// let mut rows = a.rows();
+    /// assert_eq!(rows.next(), Some(&[1, 2]));
+    /// assert_eq!(rows.next(), Some(&[3, 4]));
+    /// assert_eq!(rows.next(), None);
+    /// ```
+    fn rows(&self) -> core::slice::Iter<'_, [T; M]>;
+
+    /// Iterate over mutable references to each row of the nested array
+    ///
+    /// ```
+    /// use cl_array_ext::NestedArrayExt;
+    /// let mut a = [[1_i32, 2], [3, 4]];
+    /// a.rows_mut().for_each(|row| row.reverse());
+    /// assert_eq!(a, [[2, 1], [4, 3]]);
+    /// ```
+    fn rows_mut(&mut self) -> core::slice::IterMut<'_, [T; M]>;
+
+    /// Iterate over each column of the nested array, as an array of references
+    ///
+    /// ```
+    /// use cl_array_ext::NestedArrayExt;
+    /// let a = [[1_i32, 2], [3, 4]];
+    /// let mut cols = a.cols();
+    /// assert_eq!(cols.next(), Some([&1, &3]));
+    /// assert_eq!(cols.next(), Some([&2, &4]));
+    /// assert_eq!(cols.next(), None);
+    /// ```
+    fn cols<'a>(&'a self) -> impl DoubleEndedIterator<Item = [&'a T; N]> + ExactSizeIterator
+    where
+        T: 'a;
+
+    /// Iterate over each column of the nested array, as an array of mutable references
+    ///
+    /// ```
+    /// use cl_array_ext::NestedArrayExt;
+    /// let mut a = [[1_i32, 2], [3, 4]];
+    /// for [x, y] in a.cols_mut() {
+    ///     core::mem::swap(x, y);
+    /// }
+    /// assert_eq!(a, [[3, 4], [1, 2]]);
+    /// ```
+    fn cols_mut<'a>(
+        &'a mut self,
+    ) -> impl DoubleEndedIterator<Item = [&'a mut T; N]> + ExactSizeIterator
+    where
+        T: 'a;
+
+    /// View a nested array reference as one large array reference
+    ///
+    /// ```
+    /// use cl_array_ext::NestedArrayExt;
+    /// let a = [[1_i32, 2], [3, 4], [5, 6]];
+    /// assert_eq!(a.as_flattened(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn as_flattened(&self) -> &[T; N * M];
+
+    /// View a mutable nested array reference as one large mutable array reference
+    ///
+    /// ```
+    /// use cl_array_ext::NestedArrayExt;
+    /// let mut a = [[1_i32, 2], [3, 4], [5, 6]];
+    /// a.as_flattened_mut().reverse();
+    /// assert_eq!(a, [[6, 5], [4, 3], [2, 1]]);
+    /// ```
+    fn as_flattened_mut(&mut self) -> &mut [T; N * M];
+}
+
+impl<T, const M: usize, const N: usize> NestedArrayExt<T, M, N> for [[T; M]; N] {
+    fn flatten(self) -> [T; N * M] {
+        let arr = core::mem::ManuallyDrop::new(self);
+        unsafe { core::ptr::read(arr.as_ptr().cast()) }
+    }
+
+    fn transpose(self) -> [[T; N]; M] {
+        let arr = core::mem::ManuallyDrop::new(self);
+        let mut arr_c = core::mem::MaybeUninit::<[[T; N]; M]>::uninit();
+        let p = arr_c.as_mut_ptr().cast::<T>();
+        let q = arr.as_ptr().cast::<T>();
+
+        unsafe {
+            for i in 0..N {
+                for j in 0..M {
+                    core::ptr::copy(q.add(i * M + j), p.add(j * N + i), 1);
+                }
+            }
+
+            core::mem::MaybeUninit::assume_init(arr_c)
+        }
+    }
+
+    fn rows(&self) -> core::slice::Iter<'_, [T; M]> {
+        self.iter()
+    }
+
+    fn rows_mut(&mut self) -> core::slice::IterMut<'_, [T; M]> {
+        self.iter_mut()
+    }
+
+    fn cols<'a>(&'a self) -> impl DoubleEndedIterator<Item = [&'a T; N]> + ExactSizeIterator
+    where
+        T: 'a,
+    {
+        (0..M).map(move |j| self.each_ref().map(|row| &row[j]))
+    }
+
+    fn cols_mut<'a>(
+        &'a mut self,
+    ) -> impl DoubleEndedIterator<Item = [&'a mut T; N]> + ExactSizeIterator
+    where
+        T: 'a,
+    {
+        let p = self.as_mut_ptr().cast::<T>();
+        // every column is disjoint, so handing out each of them once is fine
+        (0..M).map(move |j| core::array::from_fn(|i| unsafe { &mut *p.add(i * M + j) }))
+    }
+
+    fn as_flattened(&self) -> &[T; N * M] {
+        unsafe { &*self.as_ptr().cast() }
+    }
+
+    fn as_flattened_mut(&mut self) -> &mut [T; N * M] {
+        unsafe { &mut *self.as_mut_ptr().cast() }
+    }
+}
+
+/// Trait that extends upon square arrays of arrays
+pub trait SquareArrayExt<T, const N: usize>: Sized {
+    /// Swap the rows and columns of the square array in place
+    ///
+    /// ```
+    /// use cl_array_ext::SquareArrayExt;
+    /// let mut a = [[1_i32, 2], [3, 4]];
+    /// a.transpose_in_place();
+    /// assert_eq!(a, [[1, 3], [2, 4]]);
+    /// ```
+    fn transpose_in_place(&mut self);
+}
+
+impl<T, const N: usize> SquareArrayExt<T, N> for [[T; N]; N] {
+    fn transpose_in_place(&mut self) {
+        let p = self.as_mut_ptr().cast::<T>();
+        for i in 0..N {
+            for j in i + 1..N {
+                unsafe { core::ptr::swap_nonoverlapping(p.add(i * N + j), p.add(j * N + i), 1) }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NestedArrayExt, SquareArrayExt};
+    use std::string::String;
+
+    #[test]
+    fn flatten() {
+        let a = [[1, 2, 3], [4, 5, 6]].flatten();
+        assert_eq!(a, [1, 2, 3, 4, 5, 6]);
+
+        let a = [[String::from("a")], [String::from("b")]].flatten();
+        assert_eq!(a, ["a", "b"]);
+    }
+
+    #[test]
+    fn transpose() {
+        let a = [[1, 2], [3, 4], [5, 6]].transpose();
+        assert_eq!(a, [[1, 3, 5], [2, 4, 6]]);
+
+        let a = [[String::from("a"), String::from("b")]].transpose();
+        assert_eq!(a, [["a"], ["b"]]);
+    }
+
+    #[test]
+    fn cols() {
+        let mut a = [[1, 2, 3], [4, 5, 6]];
+        assert_eq!(a.cols().len(), 3);
+        assert_eq!(a.cols().next_back(), Some([&3, &6]));
+
+        for (i, col) in a.cols_mut().enumerate() {
+            for x in col {
+                *x *= i;
+            }
+        }
+        assert_eq!(a, [[0, 2, 6], [0, 5, 12]]);
+    }
+
+    #[test]
+    fn transpose_in_place() {
+        let mut a = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        let b = a.transpose();
+        a.transpose_in_place();
+        assert_eq!(a, b);
+
+        let mut a = [
+            [String::from("a"), String::from("b")],
+            [String::from("c"), String::from("d")],
+        ];
+        a.transpose_in_place();
+        assert_eq!(a, [["a", "c"], ["b", "d"]]);
+    }
+}
@@ -0,0 +1,50 @@
+/// Trait that extends upon arrays of pairs
+pub trait PairArrayExt<T, U, const N: usize>: Sized {
+    /// Split an array of pairs into two arrays. The inverse of [`ArrayExt::zip`](crate::ArrayExt::zip)
+    ///
+    /// ```
+    /// use cl_array_ext::PairArrayExt;
+    /// let (a, b) = [(1_i32, 'a'), (2, 'b'), (3, 'c')].unzip();
+    /// assert_eq!(a, [1, 2, 3]);
+    /// assert_eq!(b, ['a', 'b', 'c']);
+    /// ```
+    fn unzip(self) -> ([T; N], [U; N]);
+}
+
+impl<T, U, const N: usize> PairArrayExt<T, U, N> for [(T, U); N] {
+    fn unzip(self) -> ([T; N], [U; N]) {
+        let arr = core::mem::ManuallyDrop::new(self);
+        let mut arr_a = core::mem::MaybeUninit::<[T; N]>::uninit();
+        let mut arr_b = core::mem::MaybeUninit::<[U; N]>::uninit();
+        let pa = arr_a.as_mut_ptr().cast::<T>();
+        let pb = arr_b.as_mut_ptr().cast::<U>();
+
+        unsafe {
+            for i in 0..N {
+                let (a, b) = core::ptr::read(arr.as_ptr().add(i));
+                core::ptr::write(pa.add(i), a);
+                core::ptr::write(pb.add(i), b);
+            }
+
+            (
+                core::mem::MaybeUninit::assume_init(arr_a),
+                core::mem::MaybeUninit::assume_init(arr_b),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ArrayExt, PairArrayExt};
+    use std::string::String;
+
+    #[test]
+    fn unzip() {
+        let a = [String::from("a"), String::from("b")];
+        let (a, b) = a.zip([1, 2]).unzip();
+
+        assert_eq!(a, ["a", "b"]);
+        assert_eq!(b, [1, 2]);
+    }
+}
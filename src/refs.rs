@@ -0,0 +1,40 @@
+/// Trait that extends upon arrays of references
+pub trait RefArrayExt<T, const N: usize>: Sized {
+    /// Copy each referenced value into a new array
+    ///
+    /// ```
+    /// use cl_array_ext::RefArrayExt;
+    /// let a = [1_i32, 2, 3];
+    /// assert_eq!(a.each_ref().copied(), a);
+    /// ```
+    fn copied(self) -> [T; N]
+    where
+        T: Copy;
+
+    /// Clone each referenced value into a new array
+    ///
+    /// ```
+    /// use cl_array_ext::RefArrayExt;
+    /// let a = [String::from("a"), String::from("b")];
+    /// assert_eq!(a.each_ref().cloned(), a);
+    /// ```
+    fn cloned(self) -> [T; N]
+    where
+        T: Clone;
+}
+
+impl<T, const N: usize> RefArrayExt<T, N> for [&T; N] {
+    fn copied(self) -> [T; N]
+    where
+        T: Copy,
+    {
+        self.map(|x| *x)
+    }
+
+    fn cloned(self) -> [T; N]
+    where
+        T: Clone,
+    {
+        self.map(T::clone)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::init;
+
+/// Trait for turning an array of wrapped values inside out
+pub trait SequenceExt: Sized {
+    /// The array wrapped in a single value
+    type Output;
+
+    /// Collect an array of wrapped values into a single wrapped array,
+    /// stopping at the first missing value or error
+    ///
+    /// ```
+    /// use cl_array_ext::SequenceExt;
+    /// let a = [Some(1_i32), Some(2), Some(3)].sequence();
+    /// assert_eq!(a, Some([1, 2, 3]));
+    /// let a = [Some(1_i32), None, Some(3)].sequence();
+    /// assert_eq!(a, None);
+    ///
+    /// let a = [Ok(1_i32), Err("two"), Err("three")].sequence();
+    /// assert_eq!(a, Err("two"));
+    /// ```
+    fn sequence(self) -> Self::Output;
+}
+
+impl<T, const N: usize> SequenceExt for [Option<T>; N] {
+    type Output = Option<[T; N]>;
+
+    fn sequence(self) -> Self::Output {
+        let mut iter = self.into_iter();
+        // iter has exactly N elements, so next can never fail
+        init::try_from_fn(|_| unsafe { iter.next().unwrap_unchecked() }.ok_or(())).ok()
+    }
+}
+
+impl<T, E, const N: usize> SequenceExt for [Result<T, E>; N] {
+    type Output = Result<[T; N], E>;
+
+    fn sequence(self) -> Self::Output {
+        let mut iter = self.into_iter();
+        // iter has exactly N elements, so next can never fail
+        init::try_from_fn(|_| unsafe { iter.next().unwrap_unchecked() })
+    }
+}
+
+/// Trait for pushing a wrapper around an array down onto each of its elements
+pub trait DistributeExt: Sized {
+    /// The array of wrapped values
+    type Output;
+
+    /// Turn a single wrapped array into an array of wrapped values. The inverse of [`SequenceExt::sequence`]
+    ///
+    /// ```
+    /// use cl_array_ext::DistributeExt;
+    /// let a = Some([1_i32, 2, 3]).distribute();
+    /// assert_eq!(a, [Some(1), Some(2), Some(3)]);
+    /// let a = None::<[i32; 3]>.distribute();
+    /// assert_eq!(a, [None, None, None]);
+    ///
+    /// let a = Err::<[i32; 2], _>("error").distribute();
+    /// assert_eq!(a, [Err("error"), Err("error")]);
+    /// ```
+    fn distribute(self) -> Self::Output;
+}
+
+impl<T, const N: usize> DistributeExt for Option<[T; N]> {
+    type Output = [Option<T>; N];
+
+    fn distribute(self) -> Self::Output {
+        match self {
+            Some(arr) => arr.map(Some),
+            None => core::array::from_fn(|_| None),
+        }
+    }
+}
+
+impl<T, E: Clone, const N: usize> DistributeExt for Result<[T; N], E> {
+    type Output = [Result<T, E>; N];
+
+    fn distribute(self) -> Self::Output {
+        match self {
+            Ok(arr) => arr.map(Ok),
+            Err(e) => core::array::from_fn(|_| Err(e.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DistributeExt, SequenceExt};
+    use std::rc::Rc;
+
+    #[test]
+    fn sequence_option() {
+        let rc = Rc::new(());
+        let a = [Some(rc.clone()), Some(rc.clone())].sequence();
+        assert!(a.is_some());
+        drop(a);
+
+        let a = [Some(rc.clone()), None, Some(rc.clone())].sequence();
+        assert!(a.is_none());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn sequence_result() {
+        let rc = Rc::new(());
+        let a: Result<_, ()> = [Ok(rc.clone()), Ok(rc.clone())].sequence();
+        assert!(a.is_ok());
+        drop(a);
+
+        let a = [Ok(rc.clone()), Err(1), Err(2), Ok(rc.clone())].sequence();
+        assert_eq!(a.unwrap_err(), 1);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn distribute() {
+        let a = [Some(1), Some(2)];
+        assert_eq!(a.sequence().distribute(), a);
+
+        let a: [Result<i32, i32>; 2] = [Ok(1), Err(2)];
+        assert_eq!(a.sequence().distribute(), [Err(2), Err(2)]);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::SliceN;
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+use serde::de::{Deserialize, Deserializer, Error, Expected};
+use serde::{Serialize, Serializer};
+
+/// Serializes as a sequence of all the elements
+impl<T: Serialize, const N: usize> Serialize for SliceN<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+struct AtLeast(usize);
+
+impl Expected for AtLeast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at least {} elements", self.0)
+    }
+}
+
+/// Deserializes a sequence of at least N elements, erroring on shorter ones
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Box<SliceN<T, N>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let v = Vec::<T>::deserialize(deserializer)?;
+        let len = v.len();
+        v.try_into()
+            .map_err(|_| D::Error::invalid_length(len, &AtLeast(N)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SliceN;
+    use serde_test::{assert_ser_tokens, Token};
+
+    #[test]
+    fn serialize() {
+        let a: &[_] = &[1, 2, 3];
+        let b: &SliceN<i32, 2> = a.try_into().unwrap();
+        assert_ser_tokens(
+            &b,
+            &[
+                Token::Seq { len: Some(3) },
+                Token::I32(1),
+                Token::I32(2),
+                Token::I32(3),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        use alloc::{boxed::Box, vec};
+        use serde_test::{assert_de_tokens, assert_de_tokens_error};
+
+        let tokens = [
+            Token::Seq { len: Some(3) },
+            Token::I32(1),
+            Token::I32(2),
+            Token::I32(3),
+            Token::SeqEnd,
+        ];
+
+        let b: Box<SliceN<i32, 2>> = vec![1, 2, 3].try_into().unwrap();
+        assert_de_tokens(&b, &tokens);
+        assert_de_tokens_error::<Box<SliceN<i32, 4>>>(
+            &tokens,
+            "invalid length 3, expected at least 4 elements",
+        );
+    }
+}
@@ -10,6 +10,9 @@
 ///
 /// assert_eq!(a, [3, 2, 1, 5, 4]);
 /// ```
+///
+/// `SliceN<T, N>` has the same layout as a `[T]` of length `N + tail.len()`,
+/// with the head stored first. Pointers to it carry the length of the tail as their metadata.
 #[repr(C)]
 #[allow(clippy::module_name_repetitions)]
 pub struct SliceN<T, const N: usize> {
@@ -23,23 +26,27 @@ impl<T, const N: usize> SliceN<T, N> {
     /// Increases the bounds of the slice into a new known length.
     /// # Safety
     /// There must be at least M elements in the tail available, otherwise this will result in UB
-    pub unsafe fn increase_unchecked<const M: usize>(&self) -> &SliceN<T, { N + M }> {
-        let (p, meta) = (self as *const SliceN<T, N>).to_raw_parts();
+    pub const unsafe fn increase_unchecked<const M: usize>(&self) -> &SliceN<T, { N + M }> {
+        let (p, meta) = core::ptr::from_ref(self).to_raw_parts();
         &*core::ptr::from_raw_parts(p, meta - M)
     }
 
     /// Increases the bounds of the slice into a new known length.
     /// # Safety
     /// There must be at least M elements in the tail available, otherwise this will result in UB
-    pub unsafe fn increase_unchecked_mut<const M: usize>(&mut self) -> &mut SliceN<T, { N + M }> {
-        let (p, meta) = (self as *mut SliceN<T, N>).to_raw_parts();
+    pub const unsafe fn increase_unchecked_mut<const M: usize>(
+        &mut self,
+    ) -> &mut SliceN<T, { N + M }> {
+        let (p, meta) = core::ptr::from_mut(self).to_raw_parts();
         &mut *core::ptr::from_raw_parts_mut(p, meta - M)
     }
 
     /// Increases the bounds of the slice into a new known length.
     /// # Errors
     /// There should be at least M elements in the tail available, otherwise this will return an error
-    pub fn increase<const M: usize>(&self) -> Result<&SliceN<T, { N + M }>, NotEnoughEntries> {
+    pub const fn increase<const M: usize>(
+        &self,
+    ) -> Result<&SliceN<T, { N + M }>, NotEnoughEntries> {
         if self.tail.len() < M {
             Err(NotEnoughEntries)
         } else {
@@ -50,7 +57,7 @@ impl<T, const N: usize> SliceN<T, N> {
     /// Increases the bounds of the slice into a new known length.
     /// # Errors
     /// There should be at least M elements in the tail available, otherwise this will return an error
-    pub fn increase_mut<const M: usize>(
+    pub const fn increase_mut<const M: usize>(
         &mut self,
     ) -> Result<&mut SliceN<T, { N + M }>, NotEnoughEntries> {
         if self.tail.len() < M {
@@ -60,28 +67,464 @@ impl<T, const N: usize> SliceN<T, N> {
         }
     }
 
+    /// Increases the bounds of the slice by as much as possible, up to MAX more elements.
+    pub fn increase_upto<const MAX: usize>(&self) -> IncreaseResult<'_, T, N, MAX>
+    where
+        [T; N + MAX]: Sized,
+    {
+        if self.tail.len() < MAX {
+            IncreaseResult::Partial(self, self.tail.len())
+        } else {
+            unsafe { IncreaseResult::Full(self.increase_unchecked::<MAX>()) }
+        }
+    }
+
+    /// Takes the first K elements off the front of the slice, leaving the rest in `this`.
+    /// # Errors
+    /// There should be at least K elements in the tail available so the remainder still has N elements,
+    /// otherwise this will return an error and leave `this` unchanged
+    pub fn advance<'a, const K: usize>(
+        this: &mut &'a Self,
+    ) -> Result<&'a [T; K], NotEnoughEntries> {
+        if this.tail.len() < K {
+            return Err(NotEnoughEntries);
+        }
+        let (p, meta) = core::ptr::from_ref(*this).to_raw_parts();
+        unsafe {
+            *this = &*core::ptr::from_raw_parts(p.cast::<T>().add(K), meta - K);
+            Ok(&*p.cast())
+        }
+    }
+
+    /// Takes the first K elements off the front of the mut slice, leaving the rest in `this`.
+    /// # Errors
+    /// There should be at least K elements in the tail available so the remainder still has N elements,
+    /// otherwise this will return an error and leave `this` unchanged
+    #[allow(clippy::mut_mut)] // the binding itself is updated
+    pub fn advance_mut<'a, const K: usize>(
+        this: &mut &'a mut Self,
+    ) -> Result<&'a mut [T; K], NotEnoughEntries> {
+        if this.tail.len() < K {
+            return Err(NotEnoughEntries);
+        }
+        let (p, meta) = core::ptr::from_mut(*this).to_raw_parts();
+        unsafe {
+            *this = &mut *core::ptr::from_raw_parts_mut(p.cast::<T>().add(K), meta - K);
+            Ok(&mut *p.cast())
+        }
+    }
+
     /// Decreases the bounds of the slice to a smaller known length.
-    pub fn downsize<const M: usize>(&self) -> &SliceN<T, M>
+    pub const fn downsize<const M: usize>(&self) -> &SliceN<T, M>
     where
         [T; N - M]: Sized, // M <= N
     {
-        unsafe { SliceN::<T, M>::from_unchecked(self) }
+        let (p, meta) = core::ptr::from_ref(self).to_raw_parts();
+        unsafe { &*core::ptr::from_raw_parts(p, meta + (N - M)) }
     }
 
     /// Decreases the bounds of the slice to a smaller known length.
-    pub fn downsize_mut<const M: usize>(&mut self) -> &mut SliceN<T, M>
+    pub const fn downsize_mut<const M: usize>(&mut self) -> &mut SliceN<T, M>
+    where
+        [T; N - M]: Sized, // M <= N
+    {
+        let (p, meta) = core::ptr::from_mut(self).to_raw_parts();
+        unsafe { &mut *core::ptr::from_raw_parts_mut(p, meta + (N - M)) }
+    }
+
+    /// Splits off the first K elements as an array, keeping the remainder as a [`SliceN`].
+    pub const fn split_at_const<const K: usize>(&self) -> (&[T; K], &SliceN<T, { N - K }>)
+    where
+        [T; N - K]: Sized, // K <= N
+    {
+        let (p, meta) = core::ptr::from_ref(self).to_raw_parts();
+        unsafe {
+            let rest = core::ptr::from_raw_parts(p.cast::<T>().add(K), meta);
+            (&*p.cast(), &*rest)
+        }
+    }
+
+    /// Splits off the first K elements as a mut array, keeping the remainder as a [`SliceN`].
+    pub const fn split_at_const_mut<const K: usize>(
+        &mut self,
+    ) -> (&mut [T; K], &mut SliceN<T, { N - K }>)
+    where
+        [T; N - K]: Sized, // K <= N
+    {
+        let (p, meta) = core::ptr::from_mut(self).to_raw_parts();
+        unsafe {
+            let rest = core::ptr::from_raw_parts_mut(p.cast::<T>().add(K), meta);
+            (&mut *p.cast(), &mut *rest)
+        }
+    }
+
+    /// Splits the slice at `mid`, keeping the minimum length guarantee on the left-hand side.
+    /// # Errors
+    /// `mid` must be within `N..=self.len()`, otherwise this will return an error
+    pub fn split_at_keep_head(&self, mid: usize) -> Result<(&Self, &[T]), NotEnoughEntries> {
+        if mid < N || mid > self.len() {
+            Err(NotEnoughEntries)
+        } else {
+            let (left, right) = self.split_at(mid);
+            unsafe { Ok((Self::from_unchecked(left), right)) }
+        }
+    }
+
+    /// Splits the slice at `mid`, keeping the minimum length guarantee on the left-hand side.
+    /// # Errors
+    /// `mid` must be within `N..=self.len()`, otherwise this will return an error
+    pub fn split_at_keep_head_mut(
+        &mut self,
+        mid: usize,
+    ) -> Result<(&mut Self, &mut [T]), NotEnoughEntries> {
+        if mid < N || mid > self.len() {
+            Err(NotEnoughEntries)
+        } else {
+            let (left, right) = self.split_at_mut(mid);
+            unsafe { Ok((Self::from_unchecked_mut(left), right)) }
+        }
+    }
+
+    /// Gets the element at index I, which is checked at compile time to be within the head.
+    pub fn get_const<const I: usize>(&self) -> &T
+    where
+        [T; N - I - 1]: Sized, // I < N
+    {
+        unsafe { self.head.get_unchecked(I) }
+    }
+
+    /// Gets the element at index I mutably, which is checked at compile time to be within the head.
+    pub fn get_const_mut<const I: usize>(&mut self) -> &mut T
+    where
+        [T; N - I - 1]: Sized, // I < N
+    {
+        unsafe { self.head.get_unchecked_mut(I) }
+    }
+
+    /// Overwrites the head with new values, dropping the old ones.
+    pub fn write_head(&mut self, head: [T; N]) {
+        self.head = head;
+    }
+
+    /// Overwrites the head with new values, returning the old ones.
+    pub fn replace_head(&mut self, head: [T; N]) -> [T; N] {
+        core::mem::replace(&mut self.head, head)
+    }
+
+    /// Mutably borrows the head and the tail at the same time.
+    pub fn split_head_tail_mut(&mut self) -> (&mut [T; N], &mut [T]) {
+        (&mut self.head, &mut self.tail)
+    }
+
+    /// Formats the slice with the head and tail shown separately, rather than as one list.
+    pub fn debug_head_tail(&self) -> impl fmt::Debug + '_
+    where
+        T: fmt::Debug,
+    {
+        DebugHeadTail(self)
+    }
+
+    /// Borrows each element of the head, leaving the tail untouched.
+    pub fn each_head_ref(&self) -> [&T; N] {
+        self.head.each_ref()
+    }
+
+    /// Mutably borrows each element of the head, leaving the tail untouched.
+    pub fn each_head_mut(&mut self) -> [&mut T; N] {
+        self.head.each_mut()
+    }
+
+    /// Returns the first element of the slice, which always exists when `N >= 1`.
+    pub fn first_const(&self) -> &T
+    where
+        [T; N - 1]: Sized, // N >= 1
+    {
+        &self.head[0]
+    }
+
+    /// Returns the first element of the slice mutably, which always exists when `N >= 1`.
+    pub fn first_const_mut(&mut self) -> &mut T
+    where
+        [T; N - 1]: Sized, // N >= 1
+    {
+        &mut self.head[0]
+    }
+
+    /// Returns the last element of the slice, which always exists when `N >= 1`.
+    pub fn last_const(&self) -> &T
+    where
+        [T; N - 1]: Sized, // N >= 1
+    {
+        &self[self.len() - 1]
+    }
+
+    /// Returns the last element of the slice mutably, which always exists when `N >= 1`.
+    pub fn last_const_mut(&mut self) -> &mut T
+    where
+        [T; N - 1]: Sized, // N >= 1
+    {
+        let last = self.len() - 1;
+        &mut self[last]
+    }
+
+    /// Returns the first element of the slice and the rest of the elements,
+    /// which still hold at least `N - 1` elements.
+    pub fn split_first_const(&self) -> (&T, &SliceN<T, { N - 1 }>)
+    where
+        [T; N - 1]: Sized, // N >= 1
+    {
+        let ([first], rest) = self.split_at_const::<1>();
+        (first, rest)
+    }
+
+    /// Returns the first element of the slice and the rest of the elements mutably,
+    /// which still hold at least `N - 1` elements.
+    pub fn split_first_const_mut(&mut self) -> (&mut T, &mut SliceN<T, { N - 1 }>)
+    where
+        [T; N - 1]: Sized, // N >= 1
+    {
+        let ([first], rest) = self.split_at_const_mut::<1>();
+        (first, rest)
+    }
+
+    /// Returns the last element of the slice and the rest of the elements,
+    /// which still hold at least `N - 1` elements.
+    pub fn split_last_const(&self) -> (&T, &SliceN<T, { N - 1 }>)
+    where
+        [T; N - 1]: Sized, // N >= 1
+    {
+        // removing the last element leaves the tail the same length
+        let (p, meta) = core::ptr::from_ref(self).to_raw_parts();
+        unsafe {
+            let last = p.cast::<T>().add(N - 1 + meta);
+            (&*last, &*core::ptr::from_raw_parts(p, meta))
+        }
+    }
+
+    /// Returns the last element of the slice and the rest of the elements mutably,
+    /// which still hold at least `N - 1` elements.
+    pub fn split_last_const_mut(&mut self) -> (&mut T, &mut SliceN<T, { N - 1 }>)
+    where
+        [T; N - 1]: Sized, // N >= 1
+    {
+        // removing the last element leaves the tail the same length
+        let (p, meta) = core::ptr::from_mut(self).to_raw_parts();
+        unsafe {
+            let last = p.cast::<T>().add(N - 1 + meta);
+            (&mut *last, &mut *core::ptr::from_raw_parts_mut(p, meta))
+        }
+    }
+
+    /// Splits the slice into chunks of M elements, dropping any remainder.
+    /// There are guaranteed to be at least `N / M` chunks.
+    pub fn chunks_n<const M: usize>(&self) -> &SliceN<[T; M], { N / M }>
+    where
+        [T; M - 1]: Sized, // M >= 1
+    {
+        let (p, _) = core::ptr::from_ref(self).to_raw_parts();
+        unsafe { &*core::ptr::from_raw_parts(p, self.len() / M - N / M) }
+    }
+
+    /// Splits the slice into mut chunks of M elements, dropping any remainder.
+    /// There are guaranteed to be at least `N / M` chunks.
+    pub fn chunks_n_mut<const M: usize>(&mut self) -> &mut SliceN<[T; M], { N / M }>
+    where
+        [T; M - 1]: Sized, // M >= 1
+    {
+        let chunks = self.len() / M;
+        let (p, _) = core::ptr::from_mut(self).to_raw_parts();
+        unsafe { &mut *core::ptr::from_raw_parts_mut(p, chunks - N / M) }
+    }
+
+    /// Returns an iterator over all overlapping windows of M elements.
+    /// There are guaranteed to be at least `N - M + 1` windows, so the first and last
+    /// are also available without an [`Option`] through [`first_window`](Self::first_window)
+    /// and [`last_window`](Self::last_window).
+    pub fn windows_n<const M: usize>(&self) -> WindowsN<'_, T, M>
+    where
+        [T; M - 1]: Sized, // M >= 1
+        [T; N - M]: Sized, // M <= N
+    {
+        WindowsN { rest: self }
+    }
+
+    /// Returns the first window of M elements, which always exists when `M <= N`.
+    pub fn first_window<const M: usize>(&self) -> &[T; M]
     where
+        [T; M - 1]: Sized, // M >= 1
         [T; N - M]: Sized, // M <= N
     {
-        unsafe { SliceN::<T, M>::from_unchecked_mut(self) }
+        self.split_at_const::<M>().0
+    }
+
+    /// Returns the last window of M elements, which always exists when `M <= N`.
+    pub fn last_window<const M: usize>(&self) -> &[T; M]
+    where
+        [T; M - 1]: Sized, // M >= 1
+        [T; N - M]: Sized, // M <= N
+    {
+        unsafe { &*self.as_ptr().add(self.len() - M).cast() }
+    }
+
+    /// Returns the rest of the slice after `prefix`, if the slice starts with it.
+    pub fn strip_prefix_const<const K: usize>(
+        &self,
+        prefix: &[T; K],
+    ) -> Option<&SliceN<T, { N - K }>>
+    where
+        T: PartialEq,
+        [T; N - K]: Sized, // K <= N
+    {
+        let (head, rest) = self.split_at_const::<K>();
+        (head == prefix).then_some(rest)
+    }
+
+    /// Returns true if the slice ends with `suffix`.
+    pub fn ends_with_const<const K: usize>(&self, suffix: &[T; K]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.last_chunk::<K>() == Some(suffix)
+    }
+
+    /// Returns the rest of the slice before `suffix`, if the slice ends with it.
+    pub fn strip_suffix_const<const K: usize>(
+        &self,
+        suffix: &[T; K],
+    ) -> Option<&SliceN<T, { N - K }>>
+    where
+        T: PartialEq,
+        [T; N - K]: Sized, // K <= N
+    {
+        if !self.ends_with_const(suffix) {
+            return None;
+        }
+        // removing K elements from the end leaves the tail the same length
+        let (p, meta) = core::ptr::from_ref(self).to_raw_parts();
+        unsafe { Some(&*core::ptr::from_raw_parts(p, meta)) }
+    }
+
+    /// Joins the heads of two slices into an array on the stack, ignoring both tails.
+    pub fn concat_heads<const M: usize>(&self, other: &SliceN<T, M>) -> [T; N + M]
+    where
+        T: Copy,
+    {
+        self.head.append(other.head)
+    }
+
+    /// Converts the whole slice into an array reference of a known length.
+    /// # Errors
+    /// The slice must have exactly M elements, otherwise this will return an error
+    pub fn try_as_array<const M: usize>(&self) -> Result<&[T; M], WrongLength>
+    where
+        [T; M - N]: Sized, // N <= M
+    {
+        (**self).try_into().map_err(|_| WrongLength)
+    }
+
+    /// Converts the whole slice into a mut array reference of a known length.
+    /// # Errors
+    /// The slice must have exactly M elements, otherwise this will return an error
+    pub fn try_as_array_mut<const M: usize>(&mut self) -> Result<&mut [T; M], WrongLength>
+    where
+        [T; M - N]: Sized, // N <= M
+    {
+        (&mut **self).try_into().map_err(|_| WrongLength)
+    }
+
+    /// Forms a slice with at least N elements from a pointer and a length.
+    /// # Errors
+    /// There should be at least N elements, otherwise this will return an error
+    /// # Safety
+    /// The same requirements as [`core::slice::from_raw_parts`] apply
+    pub const unsafe fn from_raw_parts<'a>(
+        data: *const T,
+        len: usize,
+    ) -> Result<&'a Self, NotEnoughEntries> {
+        if len < N {
+            Err(NotEnoughEntries)
+        } else {
+            Ok(&*core::ptr::from_raw_parts(data, len - N))
+        }
+    }
+
+    /// Forms a mut slice with at least N elements from a pointer and a length.
+    /// # Errors
+    /// There should be at least N elements, otherwise this will return an error
+    /// # Safety
+    /// The same requirements as [`core::slice::from_raw_parts_mut`] apply
+    pub const unsafe fn from_raw_parts_mut<'a>(
+        data: *mut T,
+        len: usize,
+    ) -> Result<&'a mut Self, NotEnoughEntries> {
+        if len < N {
+            Err(NotEnoughEntries)
+        } else {
+            Ok(&mut *core::ptr::from_raw_parts_mut(data, len - N))
+        }
+    }
+
+    /// Returns the number of elements in the slice, which is always at least N
+    pub const fn len(&self) -> usize {
+        let (_, meta) = core::ptr::from_ref(self).to_raw_parts();
+        meta + N
+    }
+
+    /// Returns true if the slice has no elements, which is only possible when N is 0
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a pointer to the first element of the head
+    pub const fn as_ptr(&self) -> *const T {
+        core::ptr::from_ref(self).cast()
+    }
+
+    /// Returns a mut pointer to the first element of the head
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        core::ptr::from_mut(self).cast()
+    }
+
+    /// Convert a slice into one that is guaranteed to have at least N elements.
+    /// This is the same as the [`TryFrom`] implementation, but usable in `const` contexts
+    /// # Errors
+    /// There should be at least N elements, otherwise this will return an error
+    ///
+    /// ```
+    /// use cl_array_ext::SliceN;
+    /// const A: &SliceN<u8, 2> = match SliceN::try_from_slice(b"abc") {
+    ///     Ok(a) => a,
+    ///     Err(_) => panic!(),
+    /// };
+    /// assert_eq!(A.head, *b"ab");
+    /// assert_eq!(A.len(), 3);
+    /// ```
+    pub const fn try_from_slice(slice: &[T]) -> Result<&Self, NotEnoughEntries> {
+        if slice.len() < N {
+            Err(NotEnoughEntries)
+        } else {
+            unsafe { Ok(Self::from_unchecked(slice)) }
+        }
+    }
+
+    /// Convert a mut slice into one that is guaranteed to have at least N elements.
+    /// This is the same as the [`TryFrom`] implementation, but usable in `const` contexts
+    /// # Errors
+    /// There should be at least N elements, otherwise this will return an error
+    pub const fn try_from_slice_mut(slice: &mut [T]) -> Result<&mut Self, NotEnoughEntries> {
+        if slice.len() < N {
+            Err(NotEnoughEntries)
+        } else {
+            unsafe { Ok(Self::from_unchecked_mut(slice)) }
+        }
     }
 
     /// Convert a slice into one that is guaranteed to have at least N elements
     /// # Safety
     /// The length of the slice must be >= N, otherwise this will result in UB
-    pub unsafe fn from_unchecked(slice: &[T]) -> &Self {
+    pub const unsafe fn from_unchecked(slice: &[T]) -> &Self {
         // extract the pointer metadata for the slice
-        let (p, meta) = (slice as *const [T]).to_raw_parts();
+        let (p, meta) = core::ptr::from_ref(slice).to_raw_parts();
         // convert the address and meta back into a ref
         &*core::ptr::from_raw_parts(p, meta - N)
     }
@@ -89,9 +532,9 @@ impl<T, const N: usize> SliceN<T, N> {
     /// Convert a mut slice into one that is guaranteed to have at least N elements
     /// # Safety
     /// The length of the slice must be >= N, otherwise this will result in UB
-    pub unsafe fn from_unchecked_mut(slice: &mut [T]) -> &mut Self {
+    pub const unsafe fn from_unchecked_mut(slice: &mut [T]) -> &mut Self {
         // extract the pointer metadata for the slice
-        let (p, meta) = (slice as *mut [T]).to_raw_parts();
+        let (p, meta) = core::ptr::from_mut(slice).to_raw_parts();
         // convert the address and meta back into a ref
         &mut *core::ptr::from_raw_parts_mut(p, meta - N)
     }
@@ -101,28 +544,55 @@ impl<T, const N: usize> SliceN<T, N> {
 #[derive(Debug)]
 pub struct NotEnoughEntries;
 
+/// Result of [`SliceN::increase_upto`]
+#[derive(Debug)]
+pub enum IncreaseResult<'a, T, const N: usize, const MAX: usize>
+where
+    [T; N + MAX]: Sized,
+{
+    /// All MAX extra elements were available
+    Full(&'a SliceN<T, { N + MAX }>),
+    /// Fewer than MAX extra elements were available, along with how many there were
+    Partial(&'a SliceN<T, N>, usize),
+}
+
+/// Error type returned by [`SliceN::try_as_array`] when the slice length doesn't match
+#[derive(Debug)]
+pub struct WrongLength;
+
 impl<'a, T, const N: usize> TryFrom<&'a [T]> for &'a SliceN<T, N> {
     type Error = NotEnoughEntries;
     fn try_from(value: &'a [T]) -> Result<Self, Self::Error> {
-        if value.len() < N {
-            Err(NotEnoughEntries)
-        } else {
-            unsafe { Ok(SliceN::<T, N>::from_unchecked(value)) }
-        }
+        SliceN::try_from_slice(value)
     }
 }
 
 impl<'a, T, const N: usize> TryFrom<&'a mut [T]> for &'a mut SliceN<T, N> {
     type Error = NotEnoughEntries;
     fn try_from(value: &'a mut [T]) -> Result<Self, Self::Error> {
-        if value.len() < N {
-            Err(NotEnoughEntries)
-        } else {
-            unsafe { Ok(SliceN::<T, N>::from_unchecked_mut(value)) }
-        }
+        SliceN::try_from_slice_mut(value)
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> From<&'a [T; M]> for &'a SliceN<T, N>
+where
+    [T; M - N]: Sized, // N <= M
+{
+    fn from(value: &'a [T; M]) -> Self {
+        unsafe { SliceN::<T, N>::from_unchecked(value) }
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> From<&'a mut [T; M]> for &'a mut SliceN<T, N>
+where
+    [T; M - N]: Sized, // N <= M
+{
+    fn from(value: &'a mut [T; M]) -> Self {
+        unsafe { SliceN::<T, N>::from_unchecked_mut(value) }
     }
 }
 
+use crate::ArrayExt;
 use core::fmt;
 impl<T: fmt::Debug, const N: usize> fmt::Debug for SliceN<T, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -130,13 +600,96 @@ impl<T: fmt::Debug, const N: usize> fmt::Debug for SliceN<T, N> {
     }
 }
 
+struct DebugHeadTail<'a, T, const N: usize>(&'a SliceN<T, N>);
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for DebugHeadTail<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SliceN")
+            .field("head", &self.0.head)
+            .field("tail", &&self.0.tail)
+            .finish()
+    }
+}
+
+/// Formats the bytes as a lowercase hex string. The alternate form `{:#x}` adds a `0x` prefix
+impl<const N: usize> fmt::LowerHex for SliceN<u8, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.iter().try_for_each(|b| write!(f, "{b:02x}"))
+    }
+}
+
+/// Formats the bytes as an uppercase hex string. The alternate form `{:#X}` adds a `0x` prefix
+impl<const N: usize> fmt::UpperHex for SliceN<u8, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.iter().try_for_each(|b| write!(f, "{b:02X}"))
+    }
+}
+
+use core::cmp::Ordering;
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<SliceN<U, M>> for SliceN<T, N> {
+    fn eq(&self, other: &SliceN<U, M>) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]> for SliceN<T, N> {
+    fn eq(&self, other: &[U]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<[U; M]> for SliceN<T, N> {
+    fn eq(&self, other: &[U; M]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<SliceN<U, N>> for [T] {
+    fn eq(&self, other: &SliceN<U, N>) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<SliceN<U, N>> for [T; M] {
+    fn eq(&self, other: &SliceN<U, N>) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SliceN<T, N> {}
+
+impl<T: PartialOrd, const N: usize, const M: usize> PartialOrd<SliceN<T, M>> for SliceN<T, N> {
+    fn partial_cmp(&self, other: &SliceN<T, M>) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for SliceN<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+use core::hash::{Hash, Hasher};
+impl<T: Hash, const N: usize> Hash for SliceN<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
 use core::ops::{Deref, DerefMut};
 
 impl<T, const N: usize> Deref for SliceN<T, N> {
     type Target = [T];
     fn deref(&self) -> &Self::Target {
         // extract the pointer metadata for the slice
-        let (p, meta) = (self as *const SliceN<T, N>).to_raw_parts();
+        let (p, meta) = core::ptr::from_ref(self).to_raw_parts();
         // convert the address and meta back into a ref
         unsafe { &*core::ptr::from_raw_parts(p, meta + N) }
     }
@@ -145,15 +698,95 @@ impl<T, const N: usize> Deref for SliceN<T, N> {
 impl<T, const N: usize> DerefMut for SliceN<T, N> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         // extract the pointer metadata for the slice
-        let (p, meta) = (self as *mut SliceN<T, N>).to_raw_parts();
+        let (p, meta) = core::ptr::from_mut(self).to_raw_parts();
         // convert the address and meta back into a ref
         unsafe { &mut *core::ptr::from_raw_parts_mut(p, meta + N) }
     }
 }
 
+impl<T, const N: usize> AsRef<[T]> for SliceN<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for SliceN<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+use core::borrow::{Borrow, BorrowMut};
+
+impl<T, const N: usize> Borrow<[T]> for SliceN<T, N> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for SliceN<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SliceN<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut SliceN<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over overlapping array windows of a [`SliceN`], created by [`SliceN::windows_n`]
+#[derive(Debug)]
+pub struct WindowsN<'a, T, const M: usize> {
+    rest: &'a [T],
+}
+
+impl<T, const M: usize> Clone for WindowsN<'_, T, M> {
+    fn clone(&self) -> Self {
+        WindowsN { rest: self.rest }
+    }
+}
+
+impl<'a, T, const M: usize> Iterator for WindowsN<'a, T, M> {
+    type Item = &'a [T; M];
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.rest.first_chunk()?;
+        self.rest = &self.rest[1..];
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.rest.len() + 1).saturating_sub(M);
+        (len, Some(len))
+    }
+}
+
+impl<T, const M: usize> DoubleEndedIterator for WindowsN<'_, T, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let window = self.rest.last_chunk()?;
+        self.rest = &self.rest[..self.rest.len() - 1];
+        Some(window)
+    }
+}
+
+impl<T, const M: usize> ExactSizeIterator for WindowsN<'_, T, M> {}
+impl<T, const M: usize> core::iter::FusedIterator for WindowsN<'_, T, M> {}
+
 #[cfg(test)]
 mod tests {
-    use crate::SliceN;
+    use crate::{ArrayExt, IncreaseResult, SliceN};
+    use std::{format, rc::Rc};
 
     #[test]
     fn slice_n() {
@@ -182,4 +815,390 @@ mod tests {
 
         assert_eq!(a, [9, 8, 1, 4, 5]);
     }
+
+    #[test]
+    fn fill_head_from_fn() {
+        let a: &mut [_] = &mut [1, 2, 3, 4, 5];
+        let b: &mut SliceN<_, 3> = a.try_into().unwrap();
+
+        b.head.fill_from_fn(|i| i * 10);
+
+        assert_eq!(a, [0, 10, 20, 4, 5]);
+    }
+
+    #[test]
+    fn slice_n_cmp() {
+        let a: &[_] = &[1, 2, 3, 4, 5];
+        let b: &SliceN<_, 3> = a.try_into().unwrap();
+        let c: &SliceN<_, 2> = a.try_into().unwrap();
+        let d: &SliceN<_, 2> = a[..4].try_into().unwrap();
+
+        assert_eq!(b, c);
+        assert_eq!(*b, *a);
+        assert_eq!(*a, *b);
+        assert_eq!(*b, [1, 2, 3, 4, 5]);
+        assert_eq!([1, 2, 3, 4, 5], *b);
+        assert_ne!(c, d);
+        assert!(d < c);
+        assert_eq!(d.cmp(d), core::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn slice_n_as_ref() {
+        fn sum(x: impl AsRef<[i32]>) -> i32 {
+            x.as_ref().iter().sum()
+        }
+        fn first<B: core::borrow::Borrow<[i32]> + ?Sized>(x: &B) -> i32 {
+            x.borrow()[0]
+        }
+
+        let a: &[_] = &[1, 2, 3, 4, 5];
+        let b: &SliceN<_, 3> = a.try_into().unwrap();
+        assert_eq!(sum(b), 15);
+        assert_eq!(first(b), 1);
+    }
+
+    #[test]
+    fn slice_n_into_iter() {
+        let a: &mut [_] = &mut [1, 2, 3, 4, 5];
+        let b: &mut SliceN<_, 3> = a.try_into().unwrap();
+
+        for x in &mut *b {
+            *x *= 2;
+        }
+        let mut sum = 0;
+        for x in &*b {
+            sum += x;
+        }
+
+        assert_eq!(sum, 30);
+        assert_eq!(b.into_iter().len(), 5);
+    }
+
+    #[test]
+    fn slice_n_from_array() {
+        let a = &mut [1, 2, 3, 4, 5];
+        let b: &SliceN<_, 5> = (&*a).into();
+        assert_eq!(b.head, [1, 2, 3, 4, 5]);
+
+        let b: &mut SliceN<_, 2> = a.into();
+        b.head = [0, 0];
+        assert_eq!(a, &[0, 0, 3, 4, 5]);
+    }
+
+    #[test]
+    fn slice_n_try_as_array() {
+        let a: &mut [_] = &mut [1, 2, 3, 4];
+        let b: &mut SliceN<_, 2> = a.try_into().unwrap();
+
+        assert!(b.try_as_array::<3>().is_err());
+        assert!(b.try_as_array::<5>().is_err());
+        assert_eq!(b.try_as_array::<4>().unwrap(), &[1, 2, 3, 4]);
+
+        b.try_as_array_mut::<4>().unwrap().reverse();
+        assert_eq!(a, [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn slice_n_split_at_const() {
+        let a: &[_] = &[1, 2, 3, 4, 5];
+        let b: &SliceN<_, 3> = a.try_into().unwrap();
+
+        let (head, rest) = b.split_at_const::<2>();
+        assert_eq!(head, &[1, 2]);
+        assert_eq!(rest.head, [3]);
+        assert_eq!(rest.tail, [4, 5]);
+
+        let (head, rest) = b.split_at_const::<3>();
+        assert_eq!(head, &[1, 2, 3]);
+        assert_eq!(rest, &[4, 5]);
+    }
+
+    #[test]
+    fn slice_n_split_at_const_mut() {
+        let a: &mut [_] = &mut [1, 2, 3, 4, 5];
+        let b: &mut SliceN<_, 3> = a.try_into().unwrap();
+
+        let (head, rest) = b.split_at_const_mut::<2>();
+        *head = [0, 0];
+        rest.head = [9];
+        rest.tail.reverse();
+
+        assert_eq!(a, [0, 0, 9, 5, 4]);
+    }
+
+    #[test]
+    fn slice_n_split_at_keep_head() {
+        let a: &mut [_] = &mut [1, 2, 3, 4, 5];
+        let b: &mut SliceN<_, 2> = a.try_into().unwrap();
+
+        assert!(b.split_at_keep_head(1).is_err());
+        assert!(b.split_at_keep_head(6).is_err());
+
+        let (left, right) = b.split_at_keep_head(3).unwrap();
+        assert_eq!(left, &[1, 2, 3]);
+        assert_eq!(right, [4, 5]);
+
+        let (left, right) = b.split_at_keep_head_mut(2).unwrap();
+        left.head = [0, 0];
+        right.reverse();
+        assert_eq!(a, [0, 0, 5, 4, 3]);
+    }
+
+    #[test]
+    fn slice_n_first_last() {
+        let a: &mut [_] = &mut [1, 2, 3, 4, 5];
+        let b: &mut SliceN<_, 1> = a.try_into().unwrap();
+
+        assert_eq!(b.first(), Some(&1));
+        assert_eq!(*b.first_const(), 1);
+        assert_eq!(*b.last_const(), 5);
+        let (first, rest) = b.split_first_const();
+        assert_eq!((first, &**rest), (&1, &[2, 3, 4, 5][..]));
+        let (last, rest) = b.split_last_const();
+        assert_eq!((last, &**rest), (&5, &[1, 2, 3, 4][..]));
+
+        *b.first_const_mut() = 0;
+        *b.last_const_mut() = 6;
+        *b.split_first_const_mut().0 += 1;
+        b.split_last_const_mut().1.tail.reverse();
+        assert_eq!(a, [4, 3, 2, 1, 6]);
+
+        let a: &[_] = &[1];
+        let b: &SliceN<_, 1> = a.try_into().unwrap();
+        assert_eq!(b.first_const(), b.last_const());
+        let (last, rest) = b.split_last_const();
+        assert_eq!((last, &**rest), (&1, &[][..]));
+    }
+
+    #[test]
+    fn slice_n_get_const() {
+        let a: &mut [_] = &mut [1, 2, 3, 4, 5];
+        let b: &mut SliceN<_, 3> = a.try_into().unwrap();
+
+        assert_eq!(*b.get_const::<0>(), 1);
+        assert_eq!(*b.get_const::<2>(), 3);
+
+        *b.get_const_mut::<1>() = 0;
+        assert_eq!(a, [1, 0, 3, 4, 5]);
+    }
+
+    #[test]
+    fn slice_n_split_first_recursive() {
+        fn sum<const N: usize>(s: &SliceN<i32, N>) -> i32
+        where
+            [i32; N - 1]: Sized,
+        {
+            let (first, rest) = s.split_first_const();
+            first + rest.iter().sum::<i32>()
+        }
+
+        let a: &mut [_] = &mut [1, 2, 3, 4];
+        let b: &mut SliceN<_, 2> = a.try_into().unwrap();
+        assert_eq!(sum(b), 10);
+
+        let (first, rest) = b.split_first_const_mut();
+        *first = 0;
+        rest.head = [9];
+        assert_eq!(rest.tail, [3, 4]);
+        assert_eq!(a, [0, 9, 3, 4]);
+    }
+
+    #[test]
+    fn slice_n_chunks_n() {
+        let a: &mut [_] = &mut [1, 2, 3, 4, 5, 6, 7];
+        let b: &mut SliceN<_, 4> = a.try_into().unwrap();
+
+        let c = b.chunks_n::<2>();
+        assert_eq!(c.head, [[1, 2], [3, 4]]);
+        assert_eq!(c.tail, [[5, 6]]);
+        assert_eq!(c.into_iter().count(), 3);
+
+        let c = b.chunks_n::<3>();
+        assert_eq!(c.head, [[1, 2, 3]]);
+        assert_eq!(c.tail, [[4, 5, 6]]);
+
+        for chunk in b.chunks_n_mut::<3>() {
+            chunk.reverse();
+        }
+        assert_eq!(a, [3, 2, 1, 6, 5, 4, 7]);
+    }
+
+    #[test]
+    fn slice_n_windows_n() {
+        let a: &[_] = &[1, 2, 3, 4];
+        let b: &SliceN<_, 3> = a.try_into().unwrap();
+
+        let mut w = b.windows_n::<3>();
+        assert_eq!(w.len(), 2);
+        assert_eq!(w.next(), Some(&[1, 2, 3]));
+        assert_eq!(w.next(), Some(&[2, 3, 4]));
+        assert_eq!(w.next(), None);
+
+        let w = b.windows_n::<2>();
+        assert!(w.rev().eq([&[3, 4], &[2, 3], &[1, 2]]));
+
+        assert_eq!(b.first_window::<2>(), &[1, 2]);
+        assert_eq!(b.last_window::<2>(), &[3, 4]);
+        assert_eq!(b.last_window::<3>(), &[2, 3, 4]);
+    }
+
+    #[test]
+    fn slice_n_windows_n_clone() {
+        struct NotClone(i32);
+
+        let a: &[_] = &[NotClone(1), NotClone(2)];
+        let b: &SliceN<_, 2> = a.try_into().unwrap();
+
+        let w = b.windows_n::<1>();
+        assert_eq!(w.clone().count(), 2);
+        assert_eq!(w.map(|[x]| x.0).sum::<i32>(), 3);
+    }
+
+    #[test]
+    fn slice_n_increase_upto() {
+        let a: &[_] = &[1, 2, 3, 4];
+        let b: &SliceN<_, 1> = a.try_into().unwrap();
+
+        match b.increase_upto::<2>() {
+            IncreaseResult::Full(c) => assert_eq!(c.head, [1, 2, 3]),
+            IncreaseResult::Partial(..) => panic!("expected full"),
+        }
+        match b.increase_upto::<5>() {
+            IncreaseResult::Full(_) => panic!("expected partial"),
+            IncreaseResult::Partial(c, extra) => {
+                assert_eq!(c.head, [1]);
+                assert_eq!(extra, 3);
+            }
+        }
+    }
+
+    #[test]
+    fn slice_n_advance() {
+        let a: &mut [_] = &mut [1, 2, 3, 4, 5, 6];
+        let mut b: &SliceN<_, 2> = (&*a).try_into().unwrap();
+
+        assert_eq!(SliceN::advance::<3>(&mut b).unwrap(), &[1, 2, 3]);
+        assert_eq!(b, &[4, 5, 6]);
+        assert!(SliceN::advance::<2>(&mut b).is_err());
+        assert_eq!(SliceN::advance::<1>(&mut b).unwrap(), &[4]);
+        assert_eq!(b.head, [5, 6]);
+
+        let mut b: &mut SliceN<_, 2> = a.try_into().unwrap();
+        *SliceN::advance_mut::<2>(&mut b).unwrap() = [0, 0];
+        b.head = [9, 9];
+        assert_eq!(a, [0, 0, 9, 9, 5, 6]);
+    }
+
+    #[test]
+    fn slice_n_strip_prefix_const() {
+        let a: &[_] = b"MAGIC\x01data";
+        let b: &SliceN<_, 6> = a.try_into().unwrap();
+
+        assert!(b.strip_prefix_const(b"NOPE").is_none());
+        let rest = b.strip_prefix_const(b"MAGIC").unwrap();
+        assert_eq!(rest.head, [1]);
+        assert_eq!(&rest.tail, b"data");
+    }
+
+    #[test]
+    fn slice_n_strip_suffix_const() {
+        let a: &[_] = &[1, 2, 3, 4, 5];
+        let b: &SliceN<_, 3> = a.try_into().unwrap();
+
+        assert!(b.ends_with_const(&[4, 5]));
+        assert!(!b.ends_with_const(&[3, 5]));
+        assert!(!b.ends_with_const(&[0; 6]));
+
+        assert!(b.strip_suffix_const(&[3, 4]).is_none());
+        let rest = b.strip_suffix_const(&[4, 5]).unwrap();
+        assert_eq!(rest.head, [1]);
+        assert_eq!(rest.tail, [2, 3]);
+
+        let rest = b.strip_suffix_const(&[3, 4, 5]).unwrap();
+        assert_eq!(rest, &[1, 2]);
+    }
+
+    #[test]
+    fn slice_n_concat_heads() {
+        let a: &SliceN<_, 2> = (&[1, 2, 3][..]).try_into().unwrap();
+        let b: &SliceN<_, 1> = (&[4, 5][..]).try_into().unwrap();
+        assert_eq!(a.concat_heads(b), [1, 2, 4]);
+    }
+
+    #[test]
+    fn slice_n_raw_parts() {
+        let a: &mut [_] = &mut [1, 2, 3];
+        let len = a.len();
+        let p = a.as_mut_ptr();
+
+        assert!(unsafe { SliceN::<i32, 4>::from_raw_parts(p, len) }.is_err());
+        let b = unsafe { SliceN::<i32, 2>::from_raw_parts_mut(p, len) }.unwrap();
+        assert_eq!(b.as_ptr(), p);
+        assert_eq!(b.as_mut_ptr(), p);
+        b.tail[0] = 0;
+
+        let b = unsafe { SliceN::<i32, 2>::from_raw_parts(p, len) }.unwrap();
+        assert_eq!(b.head, [1, 2]);
+        assert_eq!(b.tail, [0]);
+    }
+
+    #[test]
+    fn slice_n_each_head() {
+        let a: &mut [_] = &mut [1, 2, 3];
+        let b: &mut SliceN<_, 2> = a.try_into().unwrap();
+
+        assert_eq!(b.each_head_ref(), [&1, &2]);
+        let [x, y] = b.each_head_mut();
+        core::mem::swap(x, y);
+        assert_eq!(a, [2, 1, 3]);
+    }
+
+    #[test]
+    fn slice_n_fmt() {
+        let a: &[u8] = &[0x01, 0xab, 0xff];
+        let b: &SliceN<_, 1> = a.try_into().unwrap();
+
+        assert_eq!(format!("{b:?}"), "[1, 171, 255]");
+        assert_eq!(format!("{b:x}"), "01abff");
+        assert_eq!(format!("{b:#X}"), "0x01ABFF");
+        assert_eq!(format!("{b:#?}"), "[\n    1,\n    171,\n    255,\n]");
+        assert_eq!(
+            format!("{:?}", b.debug_head_tail()),
+            "SliceN { head: [1], tail: [171, 255] }"
+        );
+        assert_eq!(
+            format!("{:#?}", b.debug_head_tail()),
+            "SliceN {\n    head: [\n        1,\n    ],\n    tail: [\n        171,\n        255,\n    ],\n}"
+        );
+    }
+
+    #[test]
+    fn slice_n_write_head() {
+        let rc = Rc::new(());
+        let a: &mut [_] = &mut [rc.clone(), rc.clone(), rc.clone()];
+        let b: &mut SliceN<_, 2> = a.try_into().unwrap();
+
+        b.write_head([Rc::new(()), Rc::new(())]);
+        assert_eq!(Rc::strong_count(&rc), 2);
+
+        let old = b.replace_head([rc.clone(), rc.clone()]);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        assert_eq!(Rc::strong_count(&old[0]), 1);
+    }
+
+    #[test]
+    fn slice_n_split_head_tail_mut() {
+        fn write(buf: &mut [i32], value: i32) {
+            buf.fill(value);
+        }
+
+        let a: &mut [_] = &mut [1, 2, 3, 4];
+        let b: &mut SliceN<_, 1> = a.try_into().unwrap();
+
+        let (head, tail) = b.split_head_tail_mut();
+        write(head, 0);
+        write(tail, head[0] + 1);
+        assert_eq!(a, [0, 1, 1, 1]);
+    }
 }
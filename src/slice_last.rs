@@ -0,0 +1,215 @@
+use crate::NotEnoughEntries;
+
+/// A slice with at least N elements at the end. Can be dereferenced back into a regular slice on demand.
+///
+/// ```
+/// use cl_array_ext::SliceLastN;
+/// let a: &mut [_] = &mut [1, 2, 3, 4, 5];
+/// let b: &mut SliceLastN<_, 2> = a.try_into().unwrap();
+///
+/// *b.tail_mut() = [5, 4];
+/// b.body_mut().reverse();
+///
+/// assert_eq!(a, [3, 2, 1, 5, 4]);
+/// ```
+///
+/// Unsized fields must come last in a struct, so unlike [`SliceN`](crate::SliceN) the
+/// body and tail are accessed through methods rather than fields.
+#[repr(transparent)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SliceLastN<T, const N: usize>([T]);
+
+impl<T, const N: usize> SliceLastN<T, N> {
+    /// Increases the bounds of the slice into a new known length.
+    /// # Safety
+    /// There must be at least M elements in the body available, otherwise this will result in UB
+    pub const unsafe fn increase_unchecked<const M: usize>(&self) -> &SliceLastN<T, { N + M }> {
+        let (p, meta) = core::ptr::from_ref(self).to_raw_parts();
+        &*core::ptr::from_raw_parts(p, meta)
+    }
+
+    /// Increases the bounds of the slice into a new known length.
+    /// # Safety
+    /// There must be at least M elements in the body available, otherwise this will result in UB
+    pub const unsafe fn increase_unchecked_mut<const M: usize>(
+        &mut self,
+    ) -> &mut SliceLastN<T, { N + M }> {
+        let (p, meta) = core::ptr::from_mut(self).to_raw_parts();
+        &mut *core::ptr::from_raw_parts_mut(p, meta)
+    }
+
+    /// Increases the bounds of the slice into a new known length.
+    /// # Errors
+    /// There should be at least M elements in the body available, otherwise this will return an error
+    pub const fn increase<const M: usize>(
+        &self,
+    ) -> Result<&SliceLastN<T, { N + M }>, NotEnoughEntries> {
+        if self.0.len() < N + M {
+            Err(NotEnoughEntries)
+        } else {
+            unsafe { Ok(self.increase_unchecked::<M>()) }
+        }
+    }
+
+    /// Increases the bounds of the slice into a new known length.
+    /// # Errors
+    /// There should be at least M elements in the body available, otherwise this will return an error
+    pub const fn increase_mut<const M: usize>(
+        &mut self,
+    ) -> Result<&mut SliceLastN<T, { N + M }>, NotEnoughEntries> {
+        if self.0.len() < N + M {
+            Err(NotEnoughEntries)
+        } else {
+            unsafe { Ok(self.increase_unchecked_mut::<M>()) }
+        }
+    }
+
+    /// Decreases the bounds of the slice to a smaller known length.
+    pub const fn downsize<const M: usize>(&self) -> &SliceLastN<T, M>
+    where
+        [T; N - M]: Sized, // M <= N
+    {
+        unsafe { SliceLastN::<T, M>::from_unchecked(&self.0) }
+    }
+
+    /// Decreases the bounds of the slice to a smaller known length.
+    pub const fn downsize_mut<const M: usize>(&mut self) -> &mut SliceLastN<T, M>
+    where
+        [T; N - M]: Sized, // M <= N
+    {
+        unsafe { SliceLastN::<T, M>::from_unchecked_mut(&mut self.0) }
+    }
+
+    /// Body of the slice, before the last N elements. May be empty
+    pub fn body(&self) -> &[T] {
+        &self.0[..self.0.len() - N]
+    }
+
+    /// Body of the slice, before the last N elements. May be empty
+    pub fn body_mut(&mut self) -> &mut [T] {
+        let mid = self.0.len() - N;
+        &mut self.0[..mid]
+    }
+
+    /// Tail of the slice, where N items are guaranteed to exist
+    pub fn tail(&self) -> &[T; N] {
+        self.split_body_tail().1
+    }
+
+    /// Tail of the slice, where N items are guaranteed to exist
+    pub fn tail_mut(&mut self) -> &mut [T; N] {
+        self.split_body_tail_mut().1
+    }
+
+    /// Borrows the body and the tail at the same time.
+    pub fn split_body_tail(&self) -> (&[T], &[T; N]) {
+        let (body, tail) = self.0.split_at(self.0.len() - N);
+        unsafe { (body, &*tail.as_ptr().cast()) }
+    }
+
+    /// Mutably borrows the body and the tail at the same time.
+    pub fn split_body_tail_mut(&mut self) -> (&mut [T], &mut [T; N]) {
+        let mid = self.0.len() - N;
+        let (body, tail) = self.0.split_at_mut(mid);
+        unsafe { (body, &mut *tail.as_mut_ptr().cast()) }
+    }
+
+    /// Convert a slice into one that is guaranteed to have at least N elements
+    /// # Safety
+    /// The length of the slice must be >= N, otherwise this will result in UB
+    pub const unsafe fn from_unchecked(slice: &[T]) -> &Self {
+        // the slice and self share the same pointer metadata
+        let (p, meta) = core::ptr::from_ref(slice).to_raw_parts();
+        &*core::ptr::from_raw_parts(p, meta)
+    }
+
+    /// Convert a mut slice into one that is guaranteed to have at least N elements
+    /// # Safety
+    /// The length of the slice must be >= N, otherwise this will result in UB
+    pub const unsafe fn from_unchecked_mut(slice: &mut [T]) -> &mut Self {
+        // the slice and self share the same pointer metadata
+        let (p, meta) = core::ptr::from_mut(slice).to_raw_parts();
+        &mut *core::ptr::from_raw_parts_mut(p, meta)
+    }
+}
+
+impl<'a, T, const N: usize> TryFrom<&'a [T]> for &'a SliceLastN<T, N> {
+    type Error = NotEnoughEntries;
+    fn try_from(value: &'a [T]) -> Result<Self, Self::Error> {
+        if value.len() < N {
+            Err(NotEnoughEntries)
+        } else {
+            unsafe { Ok(SliceLastN::<T, N>::from_unchecked(value)) }
+        }
+    }
+}
+
+impl<'a, T, const N: usize> TryFrom<&'a mut [T]> for &'a mut SliceLastN<T, N> {
+    type Error = NotEnoughEntries;
+    fn try_from(value: &'a mut [T]) -> Result<Self, Self::Error> {
+        if value.len() < N {
+            Err(NotEnoughEntries)
+        } else {
+            unsafe { Ok(SliceLastN::<T, N>::from_unchecked_mut(value)) }
+        }
+    }
+}
+
+use core::fmt;
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SliceLastN<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+use core::ops::{Deref, DerefMut};
+
+impl<T, const N: usize> Deref for SliceLastN<T, N> {
+    type Target = [T];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for SliceLastN<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SliceLastN;
+
+    #[test]
+    fn slice_last_n() {
+        let a: &[_] = &[1, 2, 3, 4, 5];
+        let b: &SliceLastN<_, 2> = a.try_into().unwrap();
+        assert_eq!(b.body(), [1, 2, 3]);
+        assert_eq!(b.tail(), &[4, 5]);
+
+        let c = b.increase::<3>().unwrap();
+        assert!(c.body().is_empty());
+        assert_eq!(c.tail(), &[1, 2, 3, 4, 5]);
+        assert!(b.increase::<4>().is_err());
+
+        let d = c.downsize::<1>();
+        assert_eq!(d.tail(), &[5]);
+        assert_eq!(&**d, a);
+
+        assert!(<&SliceLastN<_, 6>>::try_from(a).is_err());
+    }
+
+    #[test]
+    fn slice_last_n_mut() {
+        let a: &mut [_] = &mut [1, 2, 3, 4, 5];
+        let b: &mut SliceLastN<_, 2> = a.try_into().unwrap();
+
+        let (body, tail) = b.split_body_tail_mut();
+        body.fill(0);
+        tail.swap(0, 1);
+        b.increase_mut::<1>().unwrap().tail_mut()[0] = 9;
+
+        assert_eq!(a, [0, 0, 9, 5, 4]);
+    }
+}